@@ -0,0 +1,166 @@
+use std::path::{Path, PathBuf};
+
+use advent_of_code::{Answers, Day};
+use toml::{Table, Value};
+
+/// Recorded answers, stored as `answers.toml` in the repository root.
+///
+/// Every day has a table with one entry per input file:
+///
+/// ```toml
+/// [day5."input_example.txt"]
+/// part1 = "CMZ"
+/// part2 = "MCD"
+/// ```
+///
+/// Input files inside the day's directory are named relative to it, all others by their
+/// absolute path.
+pub struct AnswerStore {
+    path: PathBuf,
+    table: Table,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+}
+
+impl AnswerStore {
+    pub fn default_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+    }
+
+    /// Loads the answers from `path`, a missing file is treated as empty.
+    pub fn load(path: &Path) -> Result<AnswerStore, String> {
+        let table = match std::fs::read_to_string(path) {
+            Ok(content) => content.parse::<Table>()
+                    .map_err(|err| format!("could not parse {}: {}", path.display(), err))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Table::new(),
+            Err(err) => return Err(format!("could not read {}: {}", path.display(), err)),
+        };
+
+        Ok(AnswerStore { path: path.to_path_buf(), table })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        std::fs::write(&self.path, self.table.to_string())
+            .map_err(|err| format!("could not write {}: {}", self.path.display(), err))
+    }
+
+    /// Names of all inputs with recorded answers for `day`.
+    pub fn inputs(&self, day: &Day) -> Vec<String> {
+        self.day_table(day)
+            .map(|inputs| inputs.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn expected(&self, day: &Day, input: &str) -> Expected {
+        let entry = self.day_table(day)
+                .and_then(|inputs| inputs.get(input))
+                .and_then(Value::as_table);
+
+        let part = |name: &str| entry
+                .and_then(|parts| parts.get(name))
+                .map(|answer| match answer {
+                    Value::String(answer) => answer.clone(),
+                    other => other.to_string(),
+                });
+
+        Expected { part1: part("part1"), part2: part("part2") }
+    }
+
+    pub fn record(&mut self, day: &Day, input: &str, answers: &Answers) {
+        let mut parts = Table::new();
+        parts.insert("part1".to_string(), Value::String(answers.part1.clone()));
+        parts.insert("part2".to_string(), Value::String(answers.part2.clone()));
+
+        let inputs = self.table.entry(day_key(day))
+                .or_insert_with(|| Value::Table(Table::new()));
+
+        if let Value::Table(inputs) = inputs {
+            inputs.insert(input.to_string(), Value::Table(parts));
+        }
+    }
+
+    fn day_table(&self, day: &Day) -> Option<&Table> {
+        self.table.get(&day_key(day)).and_then(Value::as_table)
+    }
+}
+
+fn day_key(day: &Day) -> String {
+    format!("day{}", day.number)
+}
+
+/// Name under which the answers for the input file at `path` are recorded.
+pub fn input_name(day: &Day, path: &Path) -> String {
+    let path = path.canonicalize().unwrap_or(path.to_path_buf());
+
+    match day.directory().canonicalize() {
+        Ok(directory) if path.starts_with(&directory) => {
+            path.strip_prefix(&directory).unwrap().display().to_string()
+        },
+        _ => path.display().to_string(),
+    }
+}
+
+/// Path of the input file recorded as `name`.
+pub fn input_path(day: &Day, name: &str) -> PathBuf {
+    day.directory().join(name)
+}
+
+pub fn check(expected: Option<&String>, actual: &str) -> Check {
+    match expected {
+        Some(expected) if expected == actual => Check::Pass,
+        Some(expected) => Check::Fail { expected: expected.clone(), actual: actual.to_string() },
+        None => Check::Missing { actual: actual.to_string() },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use advent_of_code::get_day;
+
+    #[test]
+    fn record_and_expected_work() {
+        let day = get_day(5).unwrap();
+        let mut store = AnswerStore { path: PathBuf::new(), table: Table::new() };
+
+        assert_eq!(store.expected(day, "input.txt"), Expected::default());
+
+        let answers = Answers { part1: "CMZ".to_string(), part2: "MCD".to_string() };
+        store.record(day, "input_example.txt", &answers);
+
+        assert_eq!(store.inputs(day), vec!["input_example.txt"]);
+        assert_eq!(store.expected(day, "input_example.txt").part1, Some("CMZ".to_string()));
+
+        let reloaded: Table = store.table.to_string().parse().unwrap();
+        assert_eq!(reloaded, store.table);
+    }
+
+    #[test]
+    fn input_name_works() {
+        let day = get_day(5).unwrap();
+
+        assert_eq!(input_name(day, &day.input_file()), "input.txt");
+        assert_eq!(input_path(day, "input.txt"), day.input_file());
+    }
+
+    #[test]
+    fn check_works() {
+        let expected = "12".to_string();
+
+        assert_eq!(check(Some(&expected), "12"), Check::Pass);
+        assert_eq!(check(Some(&expected), "13"), Check::Fail { expected: "12".to_string(), actual: "13".to_string() });
+        assert_eq!(check(None, "13"), Check::Missing { actual: "13".to_string() });
+    }
+}
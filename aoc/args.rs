@@ -0,0 +1,121 @@
+use crate::input::InputSource;
+
+/// Arguments of a day tool: positional arguments, switches like `--example` and options
+/// with a value like `--format csv`.
+#[derive(Debug)]
+pub struct ToolArgs {
+    positional: Vec<String>,
+    switches: Vec<String>,
+    values: Vec<(String, String)>,
+}
+
+impl ToolArgs {
+    /// Parses `options`, every name in `value_options` takes the following argument as its
+    /// value, all other arguments starting with `--` are switches.
+    pub fn parse(options: &[String], value_options: &[&str]) -> Result<ToolArgs, String> {
+        let mut args = ToolArgs { positional: Vec::new(), switches: Vec::new(), values: Vec::new() };
+
+        let mut options = options.iter();
+
+        while let Some(option) = options.next() {
+            if value_options.contains(&option.as_str()) {
+                let value = options.next().ok_or_else(|| format!("{} needs a value", option))?;
+
+                args.values.push((option.clone(), value.clone()));
+            } else if option.starts_with("--") {
+                args.switches.push(option.clone());
+            } else {
+                args.positional.push(option.clone());
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// Fails if a switch other than `known` was given.
+    pub fn check_switches(&self, known: &[&str]) -> Result<(), String> {
+        match self.switches.iter().find(|switch| !known.contains(&switch.as_str())) {
+            Some(switch) => Err(format!("unknown option {}", switch)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|switch| switch == name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Parses the value of option `name`, `None` if it was not given.
+    pub fn parsed_value<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.value(name)
+            .map(|value| value.parse().map_err(|_| format!("invalid value '{}' for {}", value, name)))
+            .transpose()
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(String::as_str)
+    }
+
+    /// Input given as `--example` or as positional argument `index`, a file or `-`.
+    pub fn input(&self, index: usize) -> Result<InputSource, String> {
+        if self.positional.len() > index + 1 {
+            return Err(format!("unexpected argument {}", self.positional[index + 1]));
+        }
+
+        match (self.switch("--example"), self.positional(index)) {
+            (true, Some(_)) => Err("only one input may be given".to_string()),
+            (true, None) => Ok(InputSource::Example),
+            (false, Some(arg)) => Ok(InputSource::from_arg(arg)),
+            (false, None) => Ok(InputSource::Default),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_works() {
+        let tool_args = ToolArgs::parse(&args(&["15", "--all", "--format", "csv", "in.txt"]), &["--format"]).unwrap();
+
+        assert_eq!(tool_args.positional(0), Some("15"));
+        assert!(tool_args.switch("--all"));
+        assert_eq!(tool_args.input(1), Ok(InputSource::File("in.txt".into())));
+        assert!(tool_args.check_switches(&["--all"]).is_ok());
+        assert!(tool_args.check_switches(&["--example"]).is_err());
+
+        assert!(ToolArgs::parse(&args(&["--format"]), &["--format"]).is_err());
+    }
+
+    #[test]
+    fn input_works() {
+        let tool_args = ToolArgs::parse(&args(&["15", "--example"]), &[]).unwrap();
+        assert_eq!(tool_args.input(1), Ok(InputSource::Example));
+
+        let tool_args = ToolArgs::parse(&args(&["15"]), &[]).unwrap();
+        assert_eq!(tool_args.input(1), Ok(InputSource::Default));
+
+        let tool_args = ToolArgs::parse(&args(&["15", "a.txt", "b.txt"]), &[]).unwrap();
+        assert!(tool_args.input(1).is_err());
+    }
+
+    #[test]
+    fn parsed_value_works() {
+        let tool_args = ToolArgs::parse(&args(&["--seed", "12", "--speed", "fast"]), &["--seed", "--speed"]).unwrap();
+
+        assert_eq!(tool_args.parsed_value::<u64>("--seed"), Ok(Some(12)));
+        assert!(tool_args.parsed_value::<u64>("--speed").is_err());
+        assert_eq!(tool_args.parsed_value::<u64>("--runs"), Ok(None));
+    }
+}
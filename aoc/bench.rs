@@ -0,0 +1,125 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use advent_of_code::{BenchConfig, Stats, Timings};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format '{}', expected text, json or csv", s)),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub day: u32,
+    pub timings: Timings,
+}
+
+fn phases(timings: &Timings) -> [(&'static str, Stats); 3] {
+    [("parse", timings.parse), ("part1", timings.part1), ("part2", timings.part2)]
+}
+
+pub fn format_results(results: &[BenchResult], config: &BenchConfig, format: Format) -> String {
+    let rows = results.iter()
+            .flat_map(|result| phases(&result.timings).map(|(phase, stats)| (result.day, phase, stats)));
+
+    let mut out = String::new();
+
+    match format {
+        Format::Text => {
+            writeln!(out, "{} warm-up and {} measured runs", config.warmup, config.runs).unwrap();
+            writeln!(out, "{:>3}  {:<5} {:>12} {:>12} {:>12}", "day", "phase", "min", "median", "mean").unwrap();
+
+            for (day, phase, stats) in rows {
+                writeln!(out, "{:>3}  {:<5} {:>12} {:>12} {:>12}", day, phase,
+                        format_duration(stats.min), format_duration(stats.median), format_duration(stats.mean)).unwrap();
+            }
+        },
+        Format::Json => {
+            let entries: Vec<String> = rows
+                    .map(|(day, phase, stats)| format!(
+                        "    {{\"day\": {}, \"phase\": \"{}\", \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+                        day, phase, stats.min.as_nanos(), stats.median.as_nanos(), stats.mean.as_nanos()))
+                    .collect();
+
+            writeln!(out, "{{").unwrap();
+            writeln!(out, "  \"warmup\": {},", config.warmup).unwrap();
+            writeln!(out, "  \"runs\": {},", config.runs).unwrap();
+            writeln!(out, "  \"results\": [").unwrap();
+            writeln!(out, "{}", entries.join(",\n")).unwrap();
+            writeln!(out, "  ]").unwrap();
+            writeln!(out, "}}").unwrap();
+        },
+        Format::Csv => {
+            writeln!(out, "day,phase,runs,min_ns,median_ns,mean_ns").unwrap();
+
+            for (day, phase, stats) in rows {
+                writeln!(out, "{},{},{},{},{},{}", day, phase, config.runs,
+                        stats.min.as_nanos(), stats.median.as_nanos(), stats.mean.as_nanos()).unwrap();
+            }
+        },
+    }
+
+    out
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.1?}", duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_results() -> Vec<BenchResult> {
+        let stats = Stats {
+            min: Duration::from_nanos(100),
+            median: Duration::from_nanos(150),
+            mean: Duration::from_nanos(170),
+        };
+
+        vec![BenchResult { day: 4, timings: Timings { parse: stats, part1: stats, part2: stats } }]
+    }
+
+    #[test]
+    fn format_csv_works() {
+        let config = BenchConfig { warmup: 1, runs: 5 };
+
+        let csv = format_results(&example_results(), &config, Format::Csv);
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "day,phase,runs,min_ns,median_ns,mean_ns");
+        assert_eq!(lines[2], "4,part1,5,100,150,170");
+    }
+
+    #[test]
+    fn format_json_works() {
+        let config = BenchConfig { warmup: 1, runs: 5 };
+
+        let json = format_results(&example_results(), &config, Format::Json);
+
+        assert!(json.contains("\"runs\": 5,"));
+        assert!(json.contains("{\"day\": 4, \"phase\": \"part2\", \"min_ns\": 100, \"median_ns\": 150, \"mean_ns\": 170}\n"));
+    }
+
+    #[test]
+    fn format_from_str_works() {
+        assert_eq!(Format::from_str("csv"), Ok(Format::Csv));
+        assert!(Format::from_str("xml").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use advent_of_code::day2::cipher::{score_ciphers, Cipher};
+use advent_of_code::day2::report::{breakdown, differing_rounds, format_rounds, parse_csv, Format, Histograms};
+use advent_of_code::day2::simulate::{simulate, Fixed, FrequencyCounting, Guide, Markov, Random, Simulation, Strategy};
+use advent_of_code::day2::{calculate_breakdown, calculate_breakdown2, Day2, Outcome, RoundScore, Shape};
+use advent_of_code::{get_day, Solution};
+
+use crate::args::ToolArgs;
+use crate::input::read_file;
+use crate::USAGE;
+
+/// Runs one of the day 2 tools, returns whether it succeeded.
+pub fn run(options: &[String]) -> Result<bool, String> {
+    match options {
+        [tool, options @ ..] if tool == "cipher" => cipher(&ToolArgs::parse(options, &[])?),
+        [tool, options @ ..] if tool == "report" => report(&ToolArgs::parse(options, &["--part", "--format", "--compare"])?),
+        [tool, options @ ..] if tool == "simulate" => simulate_strategies(&ToolArgs::parse(options, &["--strategy", "--seed"])?),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn read_rounds(tool_args: &ToolArgs, input_index: usize) -> Result<<Day2 as Solution>::Input, String> {
+    let day = get_day(2).unwrap();
+
+    let input_str = tool_args.input(input_index)?.read(day)?;
+
+    Day2::parse(&input_str).map_err(|err| format!("day 2: {}", err))
+}
+
+/// Lists the ciphers of the strategy guide leading to the given total score.
+fn cipher(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--all", "--example"])?;
+
+    let known_total: u32 = tool_args.positional(0)
+            .ok_or_else(|| USAGE.to_string())?
+            .parse()
+            .map_err(|_| "the total score has to be a number".to_string())?;
+
+    let rounds = read_rounds(tool_args, 1)?;
+
+    let scored = score_ciphers(&rounds).map_err(|err| format!("day 2: {}", err))?;
+
+    let consistent: Vec<&(Cipher, u32)> = scored.iter().filter(|(_, total)| *total == known_total).collect();
+
+    println!("{} of {} ciphers lead to a total score of {}", consistent.len(), scored.len(), known_total);
+
+    let found = !consistent.is_empty();
+
+    let listed = if tool_args.switch("--all") { scored.iter().collect() } else { consistent };
+
+    for (cipher, total) in listed {
+        let marker = if *total == known_total { "*" } else { " " };
+
+        println!("{} {:<30} {:>8}", marker, cipher.to_string(), total);
+    }
+
+    Ok(found)
+}
+
+const STRATEGIES: [&str; 7] = ["guide", "rock", "paper", "scissors", "frequency", "markov", "random"];
+
+fn strategy(name: &str, rounds: &[(Shape, char)], seed: u64) -> Result<Box<dyn Strategy>, String> {
+    match name {
+        "guide" => Ok(Box::new(Guide::new(rounds))),
+        "rock" => Ok(Box::new(Fixed(Shape::Rock))),
+        "paper" => Ok(Box::new(Fixed(Shape::Paper))),
+        "scissors" => Ok(Box::new(Fixed(Shape::Scissors))),
+        "frequency" => Ok(Box::new(FrequencyCounting::default())),
+        "markov" => Ok(Box::new(Markov::default())),
+        "random" => Ok(Box::new(Random::new(seed))),
+        _ => Err(format!("unknown strategy '{}', expected all or one of {}", name, STRATEGIES.join(", "))),
+    }
+}
+
+/// Plays the elf's recorded shapes against one or all strategies.
+fn simulate_strategies(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--trace", "--example"])?;
+
+    let seed: u64 = tool_args.parsed_value("--seed")?.unwrap_or(1);
+
+    let names = match tool_args.value("--strategy").unwrap_or("all") {
+        "all" => STRATEGIES.to_vec(),
+        name => vec![name],
+    };
+
+    let rounds = read_rounds(tool_args, 0)?;
+
+    let elves_shapes: Vec<Shape> = rounds.iter().map(|(shape, _)| *shape).collect();
+
+    let simulations = names.iter()
+            .map(|name| strategy(name, &rounds, seed).map(|mut strategy| simulate(&elves_shapes, strategy.as_mut())))
+            .collect::<Result<Vec<Simulation>, String>>()?;
+
+    if tool_args.switch("--trace") {
+        for simulation in &simulations {
+            println!("{}", simulation.strategy);
+
+            for (index, round) in simulation.rounds.iter().enumerate() {
+                println!("{:>6} {:<8} {:<8} {:<4} {:>2}", index + 1, format!("{:?}", round.elves_shape), format!("{:?}", round.my_shape), format!("{:?}", round.outcome), round.total());
+            }
+
+            println!();
+        }
+    }
+
+    println!("{:<20} {:>8} {:>6} {:>6} {:>6}", "strategy", "score", "wins", "draws", "losses");
+
+    for simulation in &simulations {
+        println!("{:<20} {:>8} {:>6} {:>6} {:>6}", simulation.strategy, simulation.total_score(),
+            simulation.count(Outcome::Win), simulation.count(Outcome::Draw), simulation.count(Outcome::Loss));
+    }
+
+    Ok(true)
+}
+
+/// Prints the score breakdown of every round, or only of the rounds differing from an
+/// earlier CSV export, returns whether there were no differences.
+fn report(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--histograms", "--example"])?;
+
+    let format: Format = tool_args.parsed_value("--format")?.unwrap_or(Format::Markdown);
+
+    let rounds = read_rounds(tool_args, 0)?;
+
+    let ours = match tool_args.parsed_value("--part")?.unwrap_or(2) {
+        1 => breakdown(&rounds, calculate_breakdown),
+        2 => breakdown(&rounds, calculate_breakdown2),
+        part => return Err(format!("there is no part {}", part)),
+    }.map_err(|err| format!("day 2: {}", err))?;
+
+    if tool_args.switch("--histograms") {
+        println!("{}", Histograms::new(&ours).format(format));
+
+        return Ok(true);
+    }
+
+    let Some(compare_file) = tool_args.value("--compare") else {
+        println!("{}", format_rounds(ours.iter().enumerate().map(|(index, round)| (index + 1, round)), format));
+
+        return Ok(true);
+    };
+
+    let theirs = parse_csv(&read_file(compare_file.as_ref())?).map_err(|err| format!("{}: {}", compare_file, err))?;
+
+    let differing = differing_rounds(&ours, &theirs);
+
+    println!("{} of {} rounds differ from {}", differing.len(), ours.len().max(theirs.len()), compare_file);
+
+    for (label, rounds) in [("ours", &ours), ("theirs", &theirs)] {
+        let listed: Vec<(usize, &RoundScore)> = differing.iter()
+                .filter_map(|index| rounds.get(*index).map(|round| (index + 1, round)))
+                .collect();
+
+        if !listed.is_empty() {
+            println!("\n{}\n{}", label, format_rounds(listed, format));
+        }
+    }
+
+    Ok(differing.is_empty())
+}
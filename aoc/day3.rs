@@ -0,0 +1,162 @@
+use advent_of_code::day3::check::{check_compartments, check_groups, Checked, Mode};
+use advent_of_code::day3::discover::{discover_groups, Group, Uniqueness};
+use advent_of_code::day3::priority::PriorityScheme;
+use advent_of_code::day3::{find_common_items, find_compartment_items, groups, Day3, Rucksack};
+use advent_of_code::{get_day, parse_lines, Solution};
+
+use crate::args::ToolArgs;
+use crate::input::read_file;
+use crate::USAGE;
+
+/// Runs one of the day 3 tools, returns whether it succeeded.
+pub fn run(options: &[String]) -> Result<bool, String> {
+    match options {
+        [tool, options @ ..] if tool == "check" => check(&ToolArgs::parse(options, &["--compartments", "--group-size", "--mode", "--priorities"])?),
+        [tool, options @ ..] if tool == "discover" => discover(&ToolArgs::parse(options, &["--group-size", "--priorities", "--max-steps"])?),
+        [tool, options @ ..] if tool == "shared" => shared(&ToolArgs::parse(options, &["--compartments", "--group-size", "--priorities"])?),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// The scheme given with `--priorities`, the one of the puzzle otherwise.
+fn read_scheme(tool_args: &ToolArgs) -> Result<PriorityScheme, String> {
+    match tool_args.value("--priorities") {
+        Some(file) => PriorityScheme::from_table(&read_file(file.as_ref())?).map_err(|err| format!("{}: {}", file, err)),
+        None => Ok(PriorityScheme::default()),
+    }
+}
+
+fn read_rucksacks(tool_args: &ToolArgs, input_index: usize, scheme: &PriorityScheme) -> Result<<Day3 as Solution>::Input, String> {
+    let day = get_day(3).unwrap();
+
+    let input_str = tool_args.input(input_index)?.read(day)?;
+
+    parse_lines(&input_str, |line| Rucksack::parse_with(line, scheme)).map_err(|err| format!("day 3: {}", err))
+}
+
+/// Prints the items shared by the compartments of every rucksack and by every group of
+/// rucksacks, with the sums of their priorities.
+fn shared(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--list", "--example"])?;
+
+    let compartments: usize = tool_args.parsed_value("--compartments")?.unwrap_or(2);
+    let group_size: usize = tool_args.parsed_value("--group-size")?.unwrap_or(3);
+
+    let scheme = read_scheme(tool_args)?;
+    let rucksacks = read_rucksacks(tool_args, 0, &scheme)?;
+
+    let list = tool_args.switch("--list");
+
+    let mut compartment_sum = 0;
+
+    for (index, rucksack) in rucksacks.iter().enumerate() {
+        let items = find_compartment_items(rucksack.items(), compartments, &scheme)
+                .map_err(|err| format!("day 3: {}", err.at_line(index + 1)))?;
+
+        if list {
+            println!("line {}: {}", index + 1, scheme.items(&items));
+        }
+
+        compartment_sum += items.priorities().sum::<u32>();
+    }
+
+    let mut badge_sum = 0;
+
+    for (first_line, group) in groups(&rucksacks, group_size).map_err(|err| format!("day 3: {}", err))? {
+        let items = find_common_items(&group, &scheme).map_err(|err| format!("day 3: {}", err.at_line(first_line)))?;
+
+        if list {
+            println!("lines {}..{}: {}", first_line, first_line + group.len() - 1, scheme.items(&items));
+        }
+
+        badge_sum += items.priorities().sum::<u32>();
+    }
+
+    println!("items shared by {} compartments: {}", compartments, compartment_sum);
+    println!("badges of groups of {}: {}", group_size, badge_sum);
+
+    Ok(true)
+}
+
+/// Lists every rucksack and group breaking the rules of the puzzle, returns whether there
+/// were none.
+fn check(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--example"])?;
+
+    let compartments: usize = tool_args.parsed_value("--compartments")?.unwrap_or(2);
+    let group_size: usize = tool_args.parsed_value("--group-size")?.unwrap_or(3);
+    let mode: Mode = tool_args.parsed_value("--mode")?.unwrap_or(Mode::Lenient);
+
+    let scheme = read_scheme(tool_args)?;
+    let rucksacks = read_rucksacks(tool_args, 0, &scheme)?;
+
+    let results = [
+        (format!("items shared by {} compartments", compartments), check_compartments(&rucksacks, compartments, &scheme, mode), "rucksacks"),
+        (format!("badges of groups of {}", group_size), check_groups(&rucksacks, group_size, &scheme, mode), "groups"),
+    ];
+
+    let mut valid = true;
+
+    for (title, result, left_out) in results {
+        match result {
+            Ok(Checked { sum, anomalies }) => {
+                for anomaly in &anomalies {
+                    println!("{}", anomaly);
+                }
+
+                if anomalies.is_empty() {
+                    println!("{}: {}", title, sum);
+                } else {
+                    println!("{}: {} ({} {} left out)", title, sum, anomalies.len(), left_out);
+                    valid = false;
+                }
+            },
+            Err(err) => {
+                println!("{}: {}", title, err);
+                valid = false;
+            },
+        }
+    }
+
+    Ok(valid)
+}
+
+fn print_groups(groups: &[Group]) {
+    for group in groups {
+        let lines: Vec<String> = group.lines.iter().map(|line| line.to_string()).collect();
+
+        println!("lines {}: {}", lines.join(", "), group.badge);
+    }
+}
+
+/// Finds groups with one badge each among rucksacks in any order, returns whether there is
+/// exactly one way to form them.
+fn discover(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--example"])?;
+
+    let group_size: usize = tool_args.parsed_value("--group-size")?.unwrap_or(3);
+    let max_steps: usize = tool_args.parsed_value("--max-steps")?.unwrap_or(1_000_000);
+
+    let scheme = read_scheme(tool_args)?;
+    let rucksacks = read_rucksacks(tool_args, 0, &scheme)?;
+
+    let discovery = discover_groups(&rucksacks, group_size, &scheme, max_steps).map_err(|err| format!("day 3: {}", err))?;
+
+    print_groups(&discovery.groups);
+
+    let badge_sum: u32 = discovery.groups.iter().map(|group| scheme.priority(group.badge).unwrap()).sum();
+
+    println!("badges of {} groups of {}: {}", discovery.groups.len(), group_size, badge_sum);
+
+    match &discovery.uniqueness {
+        Uniqueness::Unique => println!("these are the only groups possible"),
+        Uniqueness::Ambiguous(alternative) => {
+            println!("\nthe groups are not unique, they can also be formed like this:");
+
+            print_groups(alternative);
+        },
+        Uniqueness::Unknown => println!("gave up looking for other groups after {} steps", max_steps),
+    }
+
+    Ok(discovery.uniqueness == Uniqueness::Unique)
+}
@@ -0,0 +1,200 @@
+use advent_of_code::day4::overlaps::{find_overlaps, Relation};
+use advent_of_code::day4::plan::plan_reallocation;
+use advent_of_code::day4::render::{render_team, Scale};
+use advent_of_code::day4::{camp_coverage, camp_span, format_team, redundant_ranges, Day4};
+use advent_of_code::interval::Interval;
+use advent_of_code::interval_set::IntervalSet;
+use advent_of_code::{get_day, Solution};
+
+use crate::args::ToolArgs;
+use crate::USAGE;
+
+/// Runs one of the day 4 tools, returns whether it succeeded.
+pub fn run(options: &[String]) -> Result<bool, String> {
+    match options {
+        [tool, options @ ..] if tool == "coverage" => coverage(&ToolArgs::parse(options, &["--within", "--at-least"])?),
+        [tool, options @ ..] if tool == "overlaps" => overlaps(&ToolArgs::parse(options, &[])?),
+        [tool, options @ ..] if tool == "redundant" => redundant(&ToolArgs::parse(options, &[])?),
+        [tool, options @ ..] if tool == "plan" => plan(&ToolArgs::parse(options, &["--span"])?),
+        [tool, options @ ..] if tool == "draw" => draw(&ToolArgs::parse(options, &["--line", "--width"])?),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn read_teams(tool_args: &ToolArgs, input_index: usize) -> Result<<Day4 as Solution>::Input, String> {
+    let day = get_day(4).unwrap();
+
+    let input_str = tool_args.input(input_index)?.read(day)?;
+
+    Day4::parse(&input_str).map_err(|err| format!("day 4: {}", err))
+}
+
+/// Number of sections in `set`, followed by its runs.
+fn describe(set: &IntervalSet<u64>) -> String {
+    let runs: Vec<String> = set.runs().iter().map(Interval::to_string).collect();
+
+    if runs.is_empty() {
+        set.len().to_string()
+    } else {
+        format!("{} in {}", set.len(), runs.join(" "))
+    }
+}
+
+/// Prints how the sections of the whole camp are covered by the assignments.
+fn coverage(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--example"])?;
+
+    let at_least: usize = tool_args.parsed_value("--at-least")?.unwrap_or(2);
+
+    let teams = read_teams(tool_args, 0)?;
+
+    let within = match tool_args.parsed_value("--within")? {
+        Some(within) => within,
+        None => camp_span(&teams).ok_or("day 4: no assignments")?,
+    };
+
+    let coverage = camp_coverage(&teams);
+
+    let covered = coverage.covered();
+    let gaps = covered.gaps(&within);
+    let crowded = coverage.at_least(at_least);
+
+    println!("covered sections: {} in {} runs", covered.len(), covered.runs().len());
+    println!("sections in {} covered by nobody: {}", within, describe(&gaps));
+    println!("sections covered by at least {} elves: {}", at_least, describe(&crowded));
+
+    if let Some((depth, segment)) = coverage.max_depth() {
+        println!("most elves on a section: {} in {}", depth, segment);
+    }
+
+    Ok(true)
+}
+
+/// Lists every two elves of the camp whose assignments share sections, with the number of
+/// overlaps and containments.
+fn overlaps(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--containments", "--cross-team", "--count", "--example"])?;
+
+    let teams = read_teams(tool_args, 0)?;
+
+    let hits: Vec<_> = find_overlaps(&teams).into_iter()
+            .filter(|hit| !tool_args.switch("--containments") || hit.relation == Relation::Containment)
+            .filter(|hit| !tool_args.switch("--cross-team") || !hit.is_within_team())
+            .collect();
+
+    if !tool_args.switch("--count") {
+        let range = |line: usize, position: usize| format_team(&[teams[line - 1][position - 1]]);
+
+        for hit in &hits {
+            let verb = match hit.relation {
+                Relation::Overlap => "overlaps",
+                Relation::Containment => "contains",
+            };
+
+            println!("line {} elf {} {} {} line {} elf {} {}",
+                hit.first.line, hit.first.position, range(hit.first.line, hit.first.position),
+                verb,
+                hit.scnd.line, hit.scnd.position, range(hit.scnd.line, hit.scnd.position));
+        }
+    }
+
+    let containments = hits.iter().filter(|hit| hit.relation == Relation::Containment).count();
+
+    println!("overlapping assignments: {} ({} of them containments)", hits.len(), containments);
+
+    Ok(true)
+}
+
+/// Lists the elves of every team whose sections are covered by the rest of their team.
+fn redundant(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--example"])?;
+
+    let teams = read_teams(tool_args, 0)?;
+
+    let mut count = 0;
+    let mut team_count = 0;
+
+    for (index, team) in teams.iter().enumerate() {
+        let redundant = redundant_ranges(team);
+
+        if !redundant.is_empty() {
+            let elves: Vec<String> = redundant.iter().map(|position| format!("elf {} {}", position + 1, format_team(&[team[*position]]))).collect();
+
+            println!("line {}: {}", index + 1, elves.join(", "));
+            team_count += 1;
+        }
+
+        count += redundant.len();
+    }
+
+    println!("redundant assignments: {} in {} of {} teams", count, team_count, teams.len());
+
+    Ok(true)
+}
+
+/// Prints new assignments in the format of the input, which cover the span without
+/// overlapping and change as few elves as possible.
+fn plan(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--example"])?;
+
+    let teams = read_teams(tool_args, 0)?;
+
+    let span = match tool_args.parsed_value("--span")? {
+        Some(span) => span,
+        None => camp_span(&teams).ok_or("day 4: no assignments")?,
+    };
+
+    let plan = plan_reallocation(&teams, &span).map_err(|err| format!("day 4: {}", err))?;
+
+    for team in &plan.teams {
+        println!("{}", format_team(team));
+    }
+
+    // on stderr, so the plan can be used as input
+    eprintln!("reassigned {} of {} elves to cover {}", plan.changed.len(), teams.iter().map(Vec::len).sum::<usize>(), span);
+
+    Ok(true)
+}
+
+/// Draws the teams as strips like in the puzzle, scaled to fit the width of the terminal.
+fn draw(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--example"])?;
+
+    let line: Option<usize> = tool_args.parsed_value("--line")?;
+    let width: usize = match tool_args.parsed_value("--width")? {
+        Some(width) => width,
+        None => std::env::var("COLUMNS").ok().and_then(|columns| columns.parse().ok()).unwrap_or(80),
+    };
+
+    let mut teams = read_teams(tool_args, 0)?;
+
+    if let Some(line) = line {
+        if line == 0 || line > teams.len() {
+            return Err(format!("day 4: there is no line {}", line));
+        }
+
+        teams = vec![teams.swap_remove(line - 1)];
+    }
+
+    // sections are numbered from 1 in the puzzle
+    let span = camp_span(&teams).ok_or("day 4: no assignments")?;
+    let bounds = Interval::closed(*span.start().min(&1), *span.end());
+    let label_width = format_team(&[bounds]).len();
+    let scale = Scale::new(&bounds, width.saturating_sub(label_width + 2));
+
+    for (index, team) in teams.iter().enumerate() {
+        if line.is_none() {
+            if index > 0 {
+                println!();
+            }
+
+            println!("line {}", index + 1);
+        }
+
+        for strip in render_team(team, &scale) {
+            println!("{}", strip);
+        }
+    }
+
+    Ok(true)
+}
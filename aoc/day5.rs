@@ -0,0 +1,269 @@
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+use advent_of_code::day5::crane::{operate, Capped, Crane, CrateMover9000, CrateMover9001, Flipping, Mode, TwoArm};
+use advent_of_code::day5::history::History;
+use advent_of_code::day5::render::{draw_stacks, moved_crates};
+use advent_of_code::day5::{top_crates, Day5, Stack};
+use advent_of_code::{get_day, Solution};
+
+use crate::args::ToolArgs;
+use crate::USAGE;
+
+/// Runs one of the day 5 tools, returns whether it succeeded.
+pub fn run(options: &[String]) -> Result<bool, String> {
+    match options {
+        [tool, options @ ..] if tool == "crane" => compare_cranes(&ToolArgs::parse(options, &["--crane", "--capacity", "--arms", "--on-error"])?),
+        [tool, options @ ..] if tool == "replay" => replay(&ToolArgs::parse(options, &["--crane", "--capacity", "--arms", "--on-error", "--at"])?),
+        [tool, options @ ..] if tool == "draw" => draw(&ToolArgs::parse(options, &["--crane", "--capacity", "--arms", "--on-error", "--at", "--compare", "--delay"])?),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn read_procedure(tool_args: &ToolArgs, input_index: usize) -> Result<<Day5 as Solution>::Input, String> {
+    let day = get_day(5).unwrap();
+
+    let input_str = tool_args.input(input_index)?.read(day)?;
+
+    Day5::parse(&input_str).map_err(|err| format!("day 5: {}", err))
+}
+
+const CRANES: [&str; 5] = ["9000", "9001", "capacity", "flipping", "two-arm"];
+
+fn crane(name: &str, capacity: usize, arms: &str) -> Result<Box<dyn Crane>, String> {
+    match name {
+        "9000" => Ok(Box::new(CrateMover9000)),
+        "9001" => Ok(Box::new(CrateMover9001)),
+        "capacity" => Ok(Box::new(Capped { capacity })),
+        "flipping" => Ok(Box::new(Flipping::default())),
+        "two-arm" if arms == "two-arm" => Err("the arms of a two-arm crane cannot be two-arm cranes themselves".to_string()),
+        "two-arm" => Ok(Box::new(TwoArm { arm: crane(arms, capacity, arms)? })),
+        _ => Err(format!("unknown crane '{}', expected one of {}", name, CRANES.join(", "))),
+    }
+}
+
+/// Carries out the rearrangement procedure with one or all crane models, returns whether
+/// all moves could be carried out.
+fn compare_cranes(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--example"])?;
+
+    let mode: Mode = tool_args.parsed_value("--on-error")?.unwrap_or(Mode::Abort);
+    let capacity: usize = tool_args.parsed_value("--capacity")?.unwrap_or(3);
+    let arms = tool_args.value("--arms").unwrap_or("9001");
+
+    let names = match tool_args.value("--crane").unwrap_or("all") {
+        "all" => CRANES.to_vec(),
+        name => vec![name],
+    };
+
+    let cranes = names.iter()
+            .map(|name| crane(name, capacity, arms))
+            .collect::<Result<Vec<Box<dyn Crane>>, String>>()?;
+
+    let (stacks, moves) = read_procedure(tool_args, 0)?;
+
+    let mut valid = true;
+
+    println!("{:<20} {:>12} {:>8} {:>8} {:>8}", "crane", "top crates", "lifts", "steps", "skipped");
+
+    for mut crane in cranes {
+        let mut stacks = stacks.clone();
+
+        match operate(crane.as_mut(), &moves, &mut stacks, mode) {
+            Ok(operation) => {
+                let top = top_crates(&stacks).unwrap_or_else(|err| err.to_string());
+
+                println!("{:<20} {:>12} {:>8} {:>8} {:>8}", crane.name(), top, operation.lifts, operation.steps, operation.skipped.len());
+
+                for err in &operation.skipped {
+                    println!("  skipped {}", err);
+                }
+
+                valid &= operation.skipped.is_empty();
+            },
+            Err(err) => {
+                println!("{:<20} aborted at {}", crane.name(), err);
+                valid = false;
+            },
+        }
+    }
+
+    Ok(valid)
+}
+
+/// Top crates of all stacks, a space for an empty stack.
+fn tops(stacks: &[Stack]) -> String {
+    stacks.iter().map(|stack| stack.last().copied().unwrap_or(' ')).collect()
+}
+
+/// Prints the stacks after some of the moves and, with `--trace`, the top crates after
+/// every move.
+fn replay(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--trace", "--example"])?;
+
+    let mode: Mode = tool_args.parsed_value("--on-error")?.unwrap_or(Mode::Abort);
+    let capacity: usize = tool_args.parsed_value("--capacity")?.unwrap_or(3);
+    let mut crane = crane(tool_args.value("--crane").unwrap_or("9000"), capacity, tool_args.value("--arms").unwrap_or("9001"))?;
+    let at: Option<usize> = tool_args.parsed_value("--at")?;
+
+    let (stacks, moves) = read_procedure(tool_args, 0)?;
+
+    let mut history = History::record(crane.as_mut(), &stacks, &moves, mode).map_err(|err| format!("day 5: {}", err))?;
+
+    if tool_args.switch("--trace") {
+        history.jump_to(0);
+        println!("{:>6} {:>6}  top crates", "move", "line");
+        println!("{:>6} {:>6}  {}", 0, "", tops(history.stacks()));
+
+        for amove in &moves {
+            history.step_forward();
+
+            let line = amove.line().map_or(String::new(), |line| line.to_string());
+
+            println!("{:>6} {:>6}  {}", history.position(), line, tops(history.stacks()));
+        }
+
+        println!();
+    }
+
+    history.jump_to(at.unwrap_or(history.len()));
+
+    println!("after {} of {} moves:", history.position(), history.len());
+
+    for (index, stack) in history.stacks().iter().enumerate() {
+        println!("{}", format!("{:>3}: {}", index + 1, stack.iter().collect::<String>()).trim_end());
+    }
+
+    Ok(true)
+}
+
+/// Draws the stacks of `history` with the name of its crane on top, highlighting the top
+/// crates of a stack if given as `(stack, number of crates)`.
+fn draw_frame(name: &str, history: &History, highlight: Option<(usize, usize)>) -> Vec<String> {
+    let mut highlighted = vec![0; history.stacks().len()];
+
+    if let Some((stack, count)) = highlight {
+        highlighted[stack - 1] = count;
+    }
+
+    let mut lines = vec![name.to_string()];
+    lines.extend(draw_stacks(history.stacks(), &highlighted));
+
+    lines
+}
+
+/// Puts drawings next to each other, aligned at the bottom.
+fn side_by_side(drawings: &[Vec<String>]) -> Vec<String> {
+    let height = drawings.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..height)
+        .map(|row| {
+            let cells: Vec<String> = drawings.iter()
+                    .map(|drawing| {
+                        let width = drawing.iter().map(String::len).max().unwrap_or(0);
+                        let line = (row + drawing.len()).checked_sub(height).map_or("", |index| drawing[index].as_str());
+
+                        format!("{:<width$}", line, width = width)
+                    })
+                    .collect();
+
+            cells.join("    ").trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Draws the stacks like the input after some of the moves, or with `--animate` after
+/// every move, highlighting the crates taken before and after the move.
+fn draw(tool_args: &ToolArgs) -> Result<bool, String> {
+    tool_args.check_switches(&["--animate", "--example"])?;
+
+    let mode: Mode = tool_args.parsed_value("--on-error")?.unwrap_or(Mode::Abort);
+    let capacity: usize = tool_args.parsed_value("--capacity")?.unwrap_or(3);
+    let arms = tool_args.value("--arms").unwrap_or("9001");
+    let delay: u64 = tool_args.parsed_value("--delay")?.unwrap_or(300);
+    let at: Option<usize> = tool_args.parsed_value("--at")?;
+
+    let mut cranes = vec![crane(tool_args.value("--crane").unwrap_or("9000"), capacity, arms)?];
+
+    if let Some(name) = tool_args.value("--compare") {
+        cranes.push(crane(name, capacity, arms)?);
+    }
+
+    let (stacks, moves) = read_procedure(tool_args, 0)?;
+
+    let mut histories = cranes.iter_mut()
+            .map(|crane| History::record(crane.as_mut(), &stacks, &moves, mode).map(|history| (crane.name(), history)))
+            .collect::<Result<Vec<(String, History)>, _>>()
+            .map_err(|err| format!("day 5: {}", err))?;
+
+    let print_frame = |title: String, histories: &[(String, History)], highlight: Option<(usize, bool)>| {
+        let drawings: Vec<Vec<String>> = histories.iter()
+                .map(|(name, history)| {
+                    // the crates of move `index`, on their origin before the move
+                    let highlight = highlight.and_then(|(index, before)| {
+                        let (origin, target, count) = moved_crates(history.lifts(index))?;
+
+                        Some((if before { origin } else { target }, count))
+                    });
+
+                    draw_frame(name, history, highlight)
+                })
+                .collect();
+
+        println!("{}\n", title);
+
+        for line in side_by_side(&drawings) {
+            println!("{}", line);
+        }
+    };
+
+    let len = histories[0].1.len();
+
+    if !tool_args.switch("--animate") {
+        for (_, history) in &mut histories {
+            history.jump_to(at.unwrap_or(len));
+        }
+
+        print_frame(format!("after {} of {} moves", histories[0].1.position(), len), &histories, None);
+
+        return Ok(true);
+    }
+
+    let terminal = std::io::stdout().is_terminal();
+
+    let show = |title: String, histories: &[(String, History)], highlight: Option<(usize, bool)>| {
+        if terminal {
+            // clear the screen and go to its top left corner
+            print!("\x1b[2J\x1b[H");
+        } else {
+            println!();
+        }
+
+        print_frame(title, histories, highlight);
+        std::io::stdout().flush().ok();
+
+        std::thread::sleep(Duration::from_millis(delay));
+    };
+
+    for (_, history) in &mut histories {
+        history.jump_to(at.unwrap_or(0));
+    }
+
+    show(format!("after {} of {} moves", histories[0].1.position(), len), &histories, None);
+
+    let start = histories[0].1.position();
+
+    for (index, amove) in moves.iter().enumerate().skip(start) {
+        let line = amove.line().map_or(String::new(), |line| format!(", line {}", line));
+
+        show(format!("move {} of {}{}", index + 1, len, line), &histories, Some((index, true)));
+
+        for (_, history) in &mut histories {
+            history.step_forward();
+        }
+
+        show(format!("after {} of {} moves", index + 1, len), &histories, Some((index, false)));
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,56 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use advent_of_code::Day;
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    /// `dayN/input.txt` of the repository
+    Default,
+    /// the example input bundled with the day
+    Example,
+    /// `-` on the command line
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        }
+    }
+
+    /// Path of the input file, `None` when reading from stdin.
+    pub fn path(&self, day: &Day) -> Result<Option<PathBuf>, String> {
+        match self {
+            InputSource::Default => Ok(Some(day.input_file())),
+            InputSource::Example => day.example_file()
+                    .map(Some)
+                    .ok_or_else(|| format!("day {} has no example input", day.number)),
+            InputSource::File(path) => Ok(Some(path.clone())),
+            InputSource::Stdin => Ok(None),
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String, String> {
+        match self.path(day)? {
+            Some(path) => read_file(&path),
+            None => {
+                let mut input_str = String::new();
+
+                std::io::stdin().read_to_string(&mut input_str)
+                    .map_err(|err| format!("could not read stdin: {}", err))?;
+
+                Ok(input_str)
+            }
+        }
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))
+}
@@ -0,0 +1,399 @@
+use std::process::ExitCode;
+
+use advent_of_code::{get_day, Answers, BenchConfig, Day, DAYS};
+
+mod answers;
+mod args;
+mod bench;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod input;
+
+use answers::{check, input_name, input_path, AnswerStore, Check};
+use bench::{format_results, BenchResult, Format};
+use input::{read_file, InputSource};
+
+const USAGE: &str = "usage: aoc run <days> [--example | <input file> | -]
+       aoc verify [<days>]
+       aoc record <days> [--example | <input file>]
+       aoc bench <days> [--example | <input file> | -] [--warmup <n>] [--runs <n>] [--format text|json|csv]
+       aoc day2 cipher <total score> [--all] [--example | <input file> | -]
+       aoc day2 report [--part 1|2] [--format csv|markdown] [--histograms | --compare <csv file>] [--example | <input file> | -]
+       aoc day2 simulate [--strategy <name>] [--seed <n>] [--trace] [--example | <input file> | -]
+       aoc day3 check [--compartments <k>] [--group-size <n>] [--mode strict|lenient] [--priorities <file>] [--example | <input file> | -]
+       aoc day3 discover [--group-size <n>] [--priorities <file>] [--max-steps <n>] [--example | <input file> | -]
+       aoc day3 shared [--compartments <k>] [--group-size <n>] [--priorities <file>] [--list] [--example | <input file> | -]
+       aoc day4 coverage [--within <range>] [--at-least <k>] [--example | <input file> | -]
+       aoc day4 overlaps [--containments] [--cross-team] [--count] [--example | <input file> | -]
+       aoc day4 redundant [--example | <input file> | -]
+       aoc day4 plan [--span <range>] [--example | <input file> | -]
+       aoc day4 draw [--line <n>] [--width <columns>] [--example | <input file> | -]
+       aoc day5 crane [--crane <crane>] [--capacity <k>] [--arms <crane>] [--on-error abort|skip] [--example | <input file> | -]
+       aoc day5 replay [--crane <crane>] [--capacity <k>] [--arms <crane>] [--on-error abort|skip] [--at <n>] [--trace] [--example | <input file> | -]
+       aoc day5 draw [--crane <crane>] [--compare <crane>] [--capacity <k>] [--arms <crane>] [--on-error abort|skip] [--at <n>] [--animate] [--delay <ms>] [--example | <input file> | -]
+
+<days> is a day, `all` or a range like `2..7` (both ends included)
+<range> is a range of sections like `2-8`
+<file> of --priorities has one item and its priority per line, like `ä 53`
+<name> is `all` (the default), guide, rock, paper, scissors, frequency, markov or random
+<crane> is 9000, 9001, capacity, flipping or two-arm, whose arms are 9001 unless given
+<crane> of `day5 crane` may also be `all` (the default), `day5 replay` and `day5 draw` use 9000 unless given";
+
+struct RunArgs {
+    days: Vec<&'static Day>,
+    input: InputSource,
+}
+
+struct BenchArgs {
+    run_args: RunArgs,
+    config: BenchConfig,
+    format: Format,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match &args[..] {
+        [cmd, options @ ..] if cmd == "run" => parse_run_args(options).map(run),
+        [cmd, options @ ..] if cmd == "verify" => parse_verify_args(options).and_then(verify),
+        [cmd, options @ ..] if cmd == "record" => parse_run_args(options).and_then(record),
+        [cmd, options @ ..] if cmd == "bench" => parse_bench_args(options).map(bench),
+        [cmd, options @ ..] if cmd == "day2" => day2::run(options),
+        [cmd, options @ ..] if cmd == "day3" => day3::run(options),
+        [cmd, options @ ..] if cmd == "day4" => day4::run(options),
+        [cmd, options @ ..] if cmd == "day5" => day5::run(options),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(msg) => {
+            eprintln!("{}", msg);
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_run_args(options: &[String]) -> Result<RunArgs, String> {
+    let mut selection = None;
+    let mut input = InputSource::Default;
+
+    for option in options {
+        let source = match option.as_str() {
+            "--example" => InputSource::Example,
+            opt if opt.starts_with("--") => return Err(format!("unknown option {}\n{}", opt, USAGE)),
+            opt if selection.is_none() => {
+                selection = Some(opt);
+                continue;
+            },
+            opt => InputSource::from_arg(opt),
+        };
+
+        if input != InputSource::Default {
+            return Err(format!("only one input may be given\n{}", USAGE));
+        }
+        input = source;
+    }
+
+    let days = select_days(selection.ok_or_else(|| USAGE.to_string())?)?;
+
+    if days.len() > 1 && matches!(input, InputSource::File(_) | InputSource::Stdin) {
+        return Err("an input file can only be given for a single day".to_string());
+    }
+
+    Ok(RunArgs { days, input })
+}
+
+fn parse_bench_args(options: &[String]) -> Result<BenchArgs, String> {
+    let mut config = BenchConfig::default();
+    let mut format = Format::Text;
+    let mut run_options = Vec::new();
+
+    let mut options = options.iter();
+
+    while let Some(option) = options.next() {
+        let mut value = || options.next().ok_or_else(|| format!("{} needs a value\n{}", option, USAGE));
+
+        match option.as_str() {
+            "--warmup" => config.warmup = parse_count(value()?)?,
+            "--runs" => config.runs = parse_count(value()?)?.max(1),
+            "--format" => format = value()?.parse()?,
+            _ => run_options.push(option.clone()),
+        }
+    }
+
+    let run_args = parse_run_args(&run_options)?;
+
+    Ok(BenchArgs { run_args, config, format })
+}
+
+fn parse_count(s: &str) -> Result<usize, String> {
+    s.parse().map_err(|_| format!("'{}' is not a number of runs", s))
+}
+
+fn parse_verify_args(options: &[String]) -> Result<Vec<&'static Day>, String> {
+    match options {
+        [] => select_days("all"),
+        [selection] => select_days(selection),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Runs the selected days, returns whether all of them succeeded.
+fn run(run_args: RunArgs) -> bool {
+    let mut success = true;
+
+    for day in run_args.days {
+        match solve(day, run_args.input.read(day)) {
+            Ok(answers) => print_answers(day, &answers),
+            Err(msg) => {
+                eprintln!("{}", msg);
+
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+fn solve(day: &Day, input_str: Result<String, String>) -> Result<Answers, String> {
+    (day.run)(&input_str?).map_err(|err| format!("day {}: {}", day.number, err))
+}
+
+fn print_answers(day: &Day, answers: &Answers) {
+    println!("Day {}", day.number);
+    println!("  Part 1: {}", answers.part1);
+    println!("  Part 2: {}", answers.part2);
+}
+
+/// Times parsing and both parts of the selected days, returns whether all of them succeeded.
+fn bench(bench_args: BenchArgs) -> bool {
+    let mut results = Vec::new();
+    let mut success = true;
+
+    let run_args = bench_args.run_args;
+
+    for day in run_args.days {
+        let timings = run_args.input.read(day).and_then(|input_str| {
+            (day.bench)(&input_str, &bench_args.config)
+                .map_err(|err| format!("day {}: {}", day.number, err))
+        });
+
+        match timings {
+            Ok(timings) => results.push(BenchResult { day: day.number, timings }),
+            Err(msg) => {
+                eprintln!("{}", msg);
+
+                success = false;
+            }
+        }
+    }
+
+    print!("{}", format_results(&results, &bench_args.config, bench_args.format));
+
+    success
+}
+
+/// Checks the answers of the selected days against `answers.toml`, returns whether none of
+/// them differs.
+fn verify(days: Vec<&'static Day>) -> Result<bool, String> {
+    let store = AnswerStore::load(&AnswerStore::default_path())?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        for input in verified_inputs(day, &store) {
+            println!("Day {} {}", day.number, input);
+
+            let answers = match solve(day, read_file(&input_path(day, &input))) {
+                Ok(answers) => answers,
+                Err(msg) => {
+                    println!("  error: {}", msg);
+
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            let expected = store.expected(day, &input);
+
+            let results = [
+                check(expected.part1.as_ref(), &answers.part1),
+                check(expected.part2.as_ref(), &answers.part2),
+            ];
+
+            for (part, result) in results.into_iter().enumerate() {
+                match result {
+                    Check::Pass => {
+                        println!("  Part {}: pass", part + 1);
+                        passed += 1;
+                    },
+                    Check::Fail { expected, actual } => {
+                        println!("  Part {}: FAIL", part + 1);
+                        println!("    expected: {}", expected);
+                        println!("    actual:   {}", actual);
+                        failed += 1;
+                    },
+                    Check::Missing { actual } => {
+                        println!("  Part {}: missing (got {})", part + 1, actual);
+                        missing += 1;
+                    },
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if missing > 0 {
+        println!("record missing answers with `aoc record <day> [--example | <input file>]`");
+    }
+
+    Ok(failed == 0)
+}
+
+/// Inputs with recorded answers plus the bundled inputs of the day, so that
+/// unrecorded ones show up as missing.
+fn verified_inputs(day: &Day, store: &AnswerStore) -> Vec<String> {
+    let mut inputs = store.inputs(day);
+
+    for path in [Some(day.input_file()), day.example_file()].into_iter().flatten() {
+        let name = input_name(day, &path);
+
+        if path.exists() && !inputs.contains(&name) {
+            inputs.push(name);
+        }
+    }
+
+    inputs
+}
+
+/// Records the current answers of the selected days in `answers.toml`.
+fn record(run_args: RunArgs) -> Result<bool, String> {
+    let mut store = AnswerStore::load(&AnswerStore::default_path())?;
+
+    let mut success = true;
+
+    for day in run_args.days {
+        let path = run_args.input.path(day)
+                .and_then(|path| path.ok_or_else(|| "answers can not be recorded for stdin".to_string()));
+
+        let recorded = path.and_then(|path| {
+            let answers = solve(day, read_file(&path))?;
+
+            store.record(day, &input_name(day, &path), &answers);
+
+            Ok(answers)
+        });
+
+        match recorded {
+            Ok(answers) => print_answers(day, &answers),
+            Err(msg) => {
+                eprintln!("{}", msg);
+
+                success = false;
+            }
+        }
+    }
+
+    store.save()?;
+
+    Ok(success)
+}
+
+/// Selects the days given as `5`, `all` or a range like `2..7` (both ends included).
+fn select_days(selection: &str) -> Result<Vec<&'static Day>, String> {
+    if selection == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+
+    if let Some((first, last)) = selection.split_once("..") {
+        let first = parse_day_number(first)?;
+        let last = parse_day_number(last.trim_start_matches('='))?;
+
+        let days: Vec<&Day> = DAYS.iter()
+                .filter(|day| (first..=last).contains(&day.number))
+                .collect();
+
+        if days.is_empty() {
+            return Err(format!("no day implemented in {}", selection));
+        }
+
+        return Ok(days);
+    }
+
+    let number = parse_day_number(selection)?;
+
+    get_day(number)
+        .map(|day| vec![day])
+        .ok_or_else(|| format!("day {} is not implemented", number))
+}
+
+fn parse_day_number(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| format!("'{}' is not a day number\n{}", s, USAGE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(days: Vec<&Day>) -> Vec<u32> {
+        days.iter().map(|day| day.number).collect()
+    }
+
+    #[test]
+    fn select_days_works() {
+        assert_eq!(numbers(select_days("5").unwrap()), vec![5]);
+        assert_eq!(numbers(select_days("2..5").unwrap()), vec![2, 3, 4, 5]);
+        assert_eq!(numbers(select_days("4..=7").unwrap()), vec![4, 5, 7]);
+        assert_eq!(select_days("all").unwrap().len(), DAYS.len());
+
+        assert!(select_days("6").is_err());
+        assert!(select_days("x..3").is_err());
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_run_args_works() {
+        let run_args = parse_run_args(&args(&["5", "--example"])).unwrap();
+        assert_eq!(numbers(run_args.days), vec![5]);
+        assert_eq!(run_args.input, InputSource::Example);
+
+        let run_args = parse_run_args(&args(&["7", "-"])).unwrap();
+        assert_eq!(run_args.input, InputSource::Stdin);
+
+        let run_args = parse_run_args(&args(&["3", "other/input.txt"])).unwrap();
+        assert_eq!(run_args.input, InputSource::File("other/input.txt".into()));
+
+        let run_args = parse_run_args(&args(&["all"])).unwrap();
+        assert_eq!(run_args.input, InputSource::Default);
+
+        assert!(parse_run_args(&args(&["all", "input.txt"])).is_err());
+        assert!(parse_run_args(&args(&["5", "--example", "input.txt"])).is_err());
+        assert!(parse_run_args(&args(&["5", "--verbose"])).is_err());
+        assert!(parse_run_args(&args(&[])).is_err());
+    }
+
+    #[test]
+    fn parse_bench_args_works() {
+        let bench_args = parse_bench_args(&args(&["2..4", "--runs", "5", "--format", "csv"])).unwrap();
+        assert_eq!(numbers(bench_args.run_args.days), vec![2, 3, 4]);
+        assert_eq!(bench_args.config, BenchConfig { warmup: 3, runs: 5 });
+        assert_eq!(bench_args.format, Format::Csv);
+
+        let bench_args = parse_bench_args(&args(&["--warmup", "0", "7", "--example"])).unwrap();
+        assert_eq!(bench_args.config.warmup, 0);
+        assert_eq!(bench_args.run_args.input, InputSource::Example);
+
+        assert!(parse_bench_args(&args(&["5", "--runs"])).is_err());
+        assert!(parse_bench_args(&args(&["5", "--format", "xml"])).is_err());
+    }
+}
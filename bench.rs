@@ -0,0 +1,110 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{Error, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+    /// Runs before the measurement starts, their times are dropped.
+    pub warmup: usize,
+    /// Measured runs, at least one.
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig { warmup: 3, runs: 20 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty());
+
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+
+        Stats { min: samples[0], median, mean }
+    }
+}
+
+/// Times of the three phases of a day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+pub(crate) fn bench<S: Solution>(input: &str, config: &BenchConfig) -> Result<Timings, Error> {
+    let parse = measure(config, || S::parse(input))?;
+
+    let parsed = S::parse(input)?;
+
+    Ok(Timings {
+        parse,
+        part1: measure(config, || S::part1(&parsed))?,
+        part2: measure(config, || S::part2(&parsed))?,
+    })
+}
+
+fn measure<T>(config: &BenchConfig, mut phase: impl FnMut() -> Result<T, Error>) -> Result<Stats, Error> {
+    for _ in 0..config.warmup {
+        black_box(phase()?);
+    }
+
+    let mut samples = Vec::with_capacity(config.runs);
+
+    for _ in 0..config.runs.max(1) {
+        let start = Instant::now();
+        let result = phase();
+        samples.push(start.elapsed());
+
+        black_box(result?);
+    }
+
+    Ok(Stats::from_samples(samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples_works() {
+        let samples = [4, 1, 3, 2].iter().map(|ms| Duration::from_millis(*ms)).collect();
+
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+
+        let stats = Stats::from_samples(vec![Duration::from_millis(7), Duration::from_millis(1), Duration::from_millis(2)]);
+        assert_eq!(stats.median, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn measure_stops_on_error() {
+        let config = BenchConfig { warmup: 0, runs: 3 };
+
+        let res = measure(&config, || Err::<(), _>(Error::unsolvable("no answer")));
+
+        assert_eq!(res, Err(Error::unsolvable("no answer")));
+    }
+}
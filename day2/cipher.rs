@@ -0,0 +1,121 @@
+use std::fmt;
+
+use crate::Error;
+
+use super::{score_round, score_round_for_outcome, Outcome, Shape};
+
+const COLUMN_LETTERS: [char; 3] = ['X', 'Y', 'Z'];
+
+/// A guess what the letters X, Y and Z of the strategy guide stand for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cipher {
+    /// the shape to play, like in part 1
+    Shapes([Shape; 3]),
+    /// the outcome of the round, like in part 2
+    Outcomes([Outcome; 3]),
+}
+
+impl Cipher {
+    /// All 3! mappings of the letters to shapes followed by all mappings to outcomes.
+    pub fn all() -> Vec<Cipher> {
+        let shapes = permutations([Shape::Rock, Shape::Paper, Shape::Scissors]).map(Cipher::Shapes);
+        let outcomes = permutations([Outcome::Loss, Outcome::Draw, Outcome::Win]).map(Cipher::Outcomes);
+
+        shapes.into_iter().chain(outcomes).collect()
+    }
+
+    /// Total score of following the guide when reading it with this cipher.
+    pub fn total_score(&self, rounds: &[(Shape, char)]) -> Result<u32, Error> {
+        rounds.iter()
+            .enumerate()
+            .map(|(index, &(elves_shape, letter))| {
+                let column = COLUMN_LETTERS.iter()
+                        .position(|column_letter| *column_letter == letter)
+                        .ok_or_else(|| Error::invalid(letter, "X, Y or Z").at_column(2).at_line(index + 1))?;
+
+                match self {
+                    Cipher::Shapes(shapes) => Ok(score_round(elves_shape, shapes[column])),
+                    Cipher::Outcomes(outcomes) => score_round_for_outcome(elves_shape, outcomes[column]),
+                }
+            })
+            .sum()
+    }
+}
+
+impl fmt::Display for Cipher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let meanings: Vec<String> = match self {
+            Cipher::Shapes(shapes) => shapes.iter().map(|shape| format!("{:?}", shape)).collect(),
+            Cipher::Outcomes(outcomes) => outcomes.iter().map(|outcome| format!("{:?}", outcome)).collect(),
+        };
+
+        let mappings: Vec<String> = COLUMN_LETTERS.iter()
+                .zip(meanings)
+                .map(|(letter, meaning)| format!("{}={}", letter, meaning))
+                .collect();
+
+        write!(f, "{}", mappings.join(" "))
+    }
+}
+
+/// Every cipher with its total score for `rounds`.
+pub fn score_ciphers(rounds: &[(Shape, char)]) -> Result<Vec<(Cipher, u32)>, Error> {
+    Cipher::all().into_iter()
+        .map(|cipher| cipher.total_score(rounds).map(|total| (cipher, total)))
+        .collect()
+}
+
+/// Ciphers which lead to `known_total` for `rounds`.
+pub fn consistent_ciphers(rounds: &[(Shape, char)], known_total: u32) -> Result<Vec<Cipher>, Error> {
+    let scored = score_ciphers(rounds)?;
+
+    Ok(scored.into_iter()
+        .filter(|(_, total)| *total == known_total)
+        .map(|(cipher, _)| cipher)
+        .collect())
+}
+
+fn permutations<T: Copy>([a, b, c]: [T; 3]) -> [[T; 3]; 6] {
+    [[a, b, c], [a, c, b], [b, a, c], [b, c, a], [c, a, b], [c, b, a]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day2::EXAMPLE;
+
+    #[test]
+    fn all_works() {
+        let ciphers = Cipher::all();
+
+        assert_eq!(ciphers.len(), 12);
+        assert!(ciphers.contains(&Cipher::Shapes([Shape::Rock, Shape::Paper, Shape::Scissors])));
+        assert!(ciphers.contains(&Cipher::Outcomes([Outcome::Loss, Outcome::Draw, Outcome::Win])));
+    }
+
+    #[test]
+    fn total_score_matches_parts() {
+        let part1 = Cipher::Shapes([Shape::Rock, Shape::Paper, Shape::Scissors]);
+        let part2 = Cipher::Outcomes([Outcome::Loss, Outcome::Draw, Outcome::Win]);
+
+        assert_eq!(part1.total_score(&EXAMPLE), Ok(15));
+        assert_eq!(part2.total_score(&EXAMPLE), Ok(12));
+    }
+
+    #[test]
+    fn consistent_ciphers_works() {
+        let ciphers = consistent_ciphers(&EXAMPLE, 15).unwrap();
+
+        assert!(ciphers.contains(&Cipher::Shapes([Shape::Rock, Shape::Paper, Shape::Scissors])));
+        assert!(ciphers.iter().all(|cipher| cipher.total_score(&EXAMPLE) == Ok(15)));
+
+        assert_eq!(consistent_ciphers(&EXAMPLE, 1000), Ok(vec![]));
+    }
+
+    #[test]
+    fn cipher_display_works() {
+        let cipher = Cipher::Outcomes([Outcome::Win, Outcome::Draw, Outcome::Loss]);
+
+        assert_eq!(cipher.to_string(), "X=Win Y=Draw Z=Loss");
+    }
+}
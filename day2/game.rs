@@ -0,0 +1,238 @@
+use crate::Error;
+
+use super::Outcome;
+
+pub(crate) const OUTCOMES: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+fn outcome_index(outcome: Outcome) -> usize {
+    match outcome {
+        Outcome::Loss => 0,
+        Outcome::Draw => 1,
+        Outcome::Win => 2,
+    }
+}
+
+/// Rules of a game like Rock Paper Scissors, where every shape either beats or is beaten
+/// by every other shape.
+///
+/// Shapes are referred to by their index in `names`. The outcome of every pairing and the
+/// shape to play for a wanted outcome are computed once and then looked up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRules {
+    names: Vec<String>,
+    shape_scores: Vec<u32>,
+    /// indexed by `outcome_index`
+    outcome_scores: [u32; 3],
+    /// `outcomes[first][scnd]` is the outcome for `first` playing against `scnd`
+    outcomes: Vec<Vec<Outcome>>,
+    /// `responses[opponent][outcome_index]` is the best scoring shape reaching the outcome
+    responses: Vec<[Option<usize>; 3]>,
+}
+
+impl GameRules {
+    /// Builds the rules from a list of `(winner, loser)` pairs, which has to decide every
+    /// pairing of two different shapes exactly once.
+    ///
+    /// Shapes score their position counted from 1, outcomes 0, 3 and 6 like in the puzzle.
+    pub fn from_beats(names: &[&str], beats: &[(usize, usize)]) -> Result<GameRules, Error> {
+        let shape_count = names.len();
+
+        let mut outcomes = vec![vec![Outcome::Draw; shape_count]; shape_count];
+
+        for &(winner, loser) in beats {
+            let pairing = format!("{} beats {}", winner, loser);
+
+            if winner >= shape_count || loser >= shape_count || winner == loser {
+                return Err(Error::invalid(pairing, format!("two different shapes below {}", shape_count)));
+            }
+
+            if outcomes[winner][loser] != Outcome::Draw {
+                return Err(Error::invalid(pairing, "every pairing to be decided only once"));
+            }
+
+            outcomes[winner][loser] = Outcome::Win;
+            outcomes[loser][winner] = Outcome::Loss;
+        }
+
+        for first in 0..shape_count {
+            for scnd in first + 1..shape_count {
+                if outcomes[first][scnd] == Outcome::Draw {
+                    return Err(Error::unsolvable(format!("neither {} nor {} wins against the other", names[first], names[scnd])));
+                }
+            }
+        }
+
+        let mut rules = GameRules {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            shape_scores: (1..=shape_count as u32).collect(),
+            outcome_scores: [0, 3, 6],
+            outcomes,
+            responses: Vec::new(),
+        };
+
+        rules.update_responses();
+
+        Ok(rules)
+    }
+
+    /// Balanced game of an odd number of shapes, every shape beats the `(n - 1) / 2` shapes
+    /// listed before it, wrapping around at the start.
+    pub fn cyclic(names: &[&str]) -> Result<GameRules, Error> {
+        let shape_count = names.len();
+
+        if shape_count.is_multiple_of(2) {
+            return Err(Error::invalid(shape_count.to_string(), "an odd number of shapes"));
+        }
+
+        let beats: Vec<(usize, usize)> = (0..shape_count)
+                .flat_map(|winner| (1..=shape_count / 2).map(move |distance| (winner, (winner + shape_count - distance) % shape_count)))
+                .collect();
+
+        GameRules::from_beats(names, &beats)
+    }
+
+    pub fn rock_paper_scissors() -> GameRules {
+        GameRules::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> GameRules {
+        GameRules::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap()
+    }
+
+    pub fn with_shape_scores(mut self, scores: &[u32]) -> Result<GameRules, Error> {
+        if scores.len() != self.names.len() {
+            return Err(Error::invalid(format!("{} scores", scores.len()), format!("one score for each of the {} shapes", self.names.len())));
+        }
+
+        self.shape_scores = scores.to_vec();
+        self.update_responses();
+
+        Ok(self)
+    }
+
+    pub fn with_outcome_scores(mut self, loss: u32, draw: u32, win: u32) -> GameRules {
+        self.outcome_scores = [loss, draw, win];
+        self.update_responses();
+
+        self
+    }
+
+    fn update_responses(&mut self) {
+        let shape_count = self.names.len();
+
+        self.responses = (0..shape_count)
+                .map(|opponent| OUTCOMES.map(|outcome| {
+                    (0..shape_count)
+                        .filter(|shape| self.outcomes[*shape][opponent] == outcome)
+                        .max_by_key(|shape| (self.shape_scores[*shape], std::cmp::Reverse(*shape)))
+                }))
+                .collect();
+    }
+
+    pub fn shape_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn name(&self, shape: usize) -> &str {
+        &self.names[shape]
+    }
+
+    pub fn find_shape(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|shape_name| shape_name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn shape_score(&self, shape: usize) -> u32 {
+        self.shape_scores[shape]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        self.outcome_scores[outcome_index(outcome)]
+    }
+
+    /// Outcome for `first` when playing against `scnd`.
+    pub fn outcome(&self, first: usize, scnd: usize) -> Outcome {
+        self.outcomes[first][scnd]
+    }
+
+    /// Shape to play against `opponent` to reach `outcome`, the best scoring one if there
+    /// are several.
+    pub fn response(&self, opponent: usize, outcome: Outcome) -> Option<usize> {
+        self.responses[opponent][outcome_index(outcome)]
+    }
+
+    /// Score for playing `shape` against `opponent`.
+    pub fn score(&self, shape: usize, opponent: usize) -> u32 {
+        self.shape_score(shape) + self.outcome_score(self.outcome(shape, opponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_paper_scissors_works() {
+        let rules = GameRules::rock_paper_scissors();
+
+        assert_eq!(rules.outcome(0, 2), Outcome::Win);
+        assert_eq!(rules.outcome(2, 1), Outcome::Win);
+        assert_eq!(rules.outcome(1, 2), Outcome::Loss);
+        assert_eq!(rules.outcome(1, 1), Outcome::Draw);
+
+        assert_eq!(rules.response(0, Outcome::Win), Some(1));
+        assert_eq!(rules.score(1, 0), 8);
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock_works() {
+        let rules = GameRules::rock_paper_scissors_lizard_spock();
+
+        let lizard = rules.find_shape("lizard").unwrap();
+        let spock = rules.find_shape("Spock").unwrap();
+        let paper = rules.find_shape("Paper").unwrap();
+        let rock = rules.find_shape("Rock").unwrap();
+
+        assert_eq!(rules.outcome(lizard, spock), Outcome::Win);
+        assert_eq!(rules.outcome(lizard, paper), Outcome::Win);
+        assert_eq!(rules.outcome(lizard, rock), Outcome::Loss);
+
+        for shape in 0..rules.shape_count() {
+            let wins = (0..rules.shape_count()).filter(|other| rules.outcome(shape, *other) == Outcome::Win).count();
+            assert_eq!(wins, 2);
+        }
+    }
+
+    #[test]
+    fn response_prefers_best_score() {
+        let rules = GameRules::rock_paper_scissors_lizard_spock();
+        let rock = rules.find_shape("Rock").unwrap();
+
+        // Spock and Paper both beat Rock, Paper is listed later and scores more
+        assert_eq!(rules.response(rock, Outcome::Win), rules.find_shape("Paper"));
+
+        let rules = rules.with_shape_scores(&[1, 10, 1, 1, 1]).unwrap();
+        assert_eq!(rules.response(rock, Outcome::Win), rules.find_shape("Spock"));
+    }
+
+    #[test]
+    fn custom_scores_work() {
+        let rules = GameRules::rock_paper_scissors()
+                .with_outcome_scores(1, 2, 10)
+                .with_shape_scores(&[0, 0, 5])
+                .unwrap();
+
+        assert_eq!(rules.score(2, 1), 15);
+        assert_eq!(rules.score(0, 0), 2);
+
+        assert!(GameRules::rock_paper_scissors().with_shape_scores(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        assert!(GameRules::cyclic(&["A", "B", "C", "D"]).is_err());
+        assert!(GameRules::from_beats(&["A", "B", "C"], &[(0, 1), (1, 2)]).is_err());
+        assert!(GameRules::from_beats(&["A", "B"], &[(0, 1), (1, 0)]).is_err());
+        assert!(GameRules::from_beats(&["A", "B"], &[(0, 0)]).is_err());
+        assert!(GameRules::from_beats(&["A", "B", "C"], &[(0, 1), (1, 2), (2, 0)]).is_ok());
+    }
+}
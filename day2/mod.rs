@@ -0,0 +1,317 @@
+use std::sync::OnceLock;
+
+use crate::{parse_lines, Error, Solution};
+
+pub mod cipher;
+pub mod game;
+pub mod report;
+pub mod simulate;
+
+use game::GameRules;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Shape {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl From<Shape> for u32 {
+    fn from(shape: Shape) -> u32 {
+        shape as u32
+    }
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// Index of the shape in `GameRules::rock_paper_scissors`.
+    pub fn index(self) -> usize {
+        self as usize - 1
+    }
+
+    pub fn from_index(index: usize) -> Option<Shape> {
+        Shape::ALL.get(index).copied()
+    }
+}
+
+impl TryFrom<char> for Shape {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' | 'X' => Ok(Shape::Rock),
+            'B' | 'Y' => Ok(Shape::Paper),
+            'C' | 'Z' => Ok(Shape::Scissors),
+            _ => Err(Error::invalid(c, "a shape (A, B, C, X, Y or Z)")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Outcome {
+    Loss = 0,
+    Draw = 3,
+    Win = 6
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Outcome::Loss),
+            'Y' => Ok(Outcome::Draw),
+            'Z' => Ok(Outcome::Win),
+            _ => Err(Error::invalid(c, "an outcome (X, Y or Z)")),
+        }
+    }
+}
+
+impl From<Outcome> for u32 {
+    fn from(outcome: Outcome) -> u32 {
+        outcome as u32
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Shape, char)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLE_FILE: Option<&'static str> = Some("input_example.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, parse_round)
+    }
+
+    fn part1(rounds: &Self::Input) -> Result<u32, Error> {
+        rounds.iter().map(calculate_score).sum()
+    }
+
+    fn part2(rounds: &Self::Input) -> Result<u32, Error> {
+        rounds.iter().map(calculate_score2).sum()
+    }
+}
+
+/// Splits a tip like `A Y` into its two columns.
+fn split_tip(tip: &str) -> Result<(char, char), Error> {
+    let mut chars = tip.chars();
+
+    match (chars.next(), chars.next(), chars.next(), chars.next()) {
+        (Some(first), Some(' '), Some(second), None) => Ok((first, second)),
+        _ => Err(Error::invalid(tip, "two columns like 'A Y'")),
+    }
+}
+
+/// Parses a tip into the elf's shape and the second column, which is a shape in part 1
+/// and an outcome in part 2.
+pub fn parse_round(tip: &str) -> Result<(Shape, char), Error> {
+    let (elves_char, scnd_char) = split_tip(tip)?;
+
+    let elves_shape = Shape::try_from(elves_char)?;
+
+    if !matches!(scnd_char, 'X' | 'Y' | 'Z') {
+        return Err(Error::invalid(scnd_char, "X, Y or Z").at_column(2));
+    }
+
+    Ok((elves_shape, scnd_char))
+}
+
+fn rules() -> &'static GameRules {
+    static RULES: OnceLock<GameRules> = OnceLock::new();
+
+    RULES.get_or_init(GameRules::rock_paper_scissors)
+}
+
+/// Score for playing `my_shape` against `elves_shape`.
+pub fn score_round(elves_shape: Shape, my_shape: Shape) -> u32 {
+    rules().score(my_shape.index(), elves_shape.index())
+}
+
+/// Shape to play against `elves_shape` to reach `target_outcome`.
+fn response(elves_shape: Shape, target_outcome: Outcome) -> Result<Shape, Error> {
+    rules().response(elves_shape.index(), target_outcome)
+        .and_then(Shape::from_index)
+        .ok_or_else(|| Error::unsolvable(format!("no shape reaches {:?} against {:?}", target_outcome, elves_shape)))
+}
+
+/// Score for playing the shape reaching `target_outcome` against `elves_shape`.
+pub fn score_round_for_outcome(elves_shape: Shape, target_outcome: Outcome) -> Result<u32, Error> {
+    let my_shape = response(elves_shape, target_outcome)?;
+
+    Ok(score_round(elves_shape, my_shape))
+}
+
+/// How the score of a single round comes about.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundScore {
+    pub elves_shape: Shape,
+    pub my_shape: Shape,
+    pub outcome: Outcome,
+    pub shape_points: u32,
+    pub outcome_points: u32,
+}
+
+impl RoundScore {
+    pub fn new(elves_shape: Shape, my_shape: Shape) -> RoundScore {
+        let outcome = does_first_shape_win(&my_shape, &elves_shape);
+
+        RoundScore {
+            elves_shape,
+            my_shape,
+            outcome,
+            shape_points: rules().shape_score(my_shape.index()),
+            outcome_points: rules().outcome_score(outcome),
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.shape_points + self.outcome_points
+    }
+}
+
+fn calculate_score(round: &(Shape, char)) -> Result<u32, Error> {
+    Ok(calculate_breakdown(round)?.total())
+}
+
+fn calculate_score2(round: &(Shape, char)) -> Result<u32, Error> {
+    Ok(calculate_breakdown2(round)?.total())
+}
+
+/// Round of the strategy guide read like in part 1, the second column is my shape.
+pub fn calculate_breakdown(&(elves_shape, my_char): &(Shape, char)) -> Result<RoundScore, Error> {
+    let my_shape = Shape::try_from(my_char)?;
+
+    Ok(RoundScore::new(elves_shape, my_shape))
+}
+
+/// Round of the strategy guide read like in part 2, the second column is the outcome.
+pub fn calculate_breakdown2(&(elves_shape, outcome_char): &(Shape, char)) -> Result<RoundScore, Error> {
+    let target_outcome = Outcome::try_from(outcome_char)?;
+
+    Ok(RoundScore::new(elves_shape, response(elves_shape, target_outcome)?))
+}
+
+pub fn does_first_shape_win(first_shape: &Shape, scnd_shape: &Shape) -> Outcome {
+    rules().outcome(first_shape.index(), scnd_shape.index())
+}
+
+/// Rounds of the strategy guide of the puzzle example.
+#[cfg(test)]
+pub(crate) const EXAMPLE: [(Shape, char); 3] = [(Shape::Rock, 'Y'), (Shape::Paper, 'X'), (Shape::Scissors, 'Z')];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn shape_from_char_works() {
+        let result = Shape::try_from('A').unwrap();
+        assert_eq!(result, Shape::Rock);
+
+        let result = Shape::try_from('B').unwrap();
+        assert_eq!(result, Shape::Paper);
+
+        assert!(Shape::try_from('D').is_err());
+    }
+
+    #[test]
+    fn outcome_from_char_works() {
+        assert_eq!(Outcome::try_from('X').unwrap(), Outcome::Loss);
+        assert!(Outcome::try_from('A').is_err());
+    }
+
+    #[test]
+    fn does_first_shape_win_work() {
+        assert_eq!(does_first_shape_win(&Shape::Rock, &Shape::Paper), Outcome::Loss);
+        assert_eq!(does_first_shape_win(&Shape::Scissors, &Shape::Paper), Outcome::Win);
+        assert_eq!(does_first_shape_win(&Shape::Rock, &Shape::Rock), Outcome::Draw);
+
+    }
+
+    #[test]
+    fn shape_index_works() {
+        for shape in Shape::ALL {
+            assert_eq!(Shape::from_index(shape.index()), Some(shape));
+            assert_eq!(rules().shape_score(shape.index()), shape.into());
+        }
+    }
+
+    #[test]
+    fn shape_into_works() {
+        let result: u32 = Shape::Rock.into();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn outcome_into_works() {
+        let result: u32 = Outcome::Win.into();
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn calculate_score_works() {
+        let result = calculate_score(&(Shape::Rock, 'Y')).unwrap();
+        assert_eq!(result, 8);
+        
+        let result = calculate_score(&(Shape::Paper, 'X')).unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn calculate_score2_works() {
+        let result = calculate_score2(&(Shape::Rock, 'Y')).unwrap();
+        assert_eq!(result, 4);
+        
+        let result = calculate_score2(&(Shape::Paper, 'X')).unwrap();
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn calculate_breakdown_works() {
+        let result = calculate_breakdown(&(Shape::Rock, 'Y')).unwrap();
+        assert_eq!(result.total(), 8);
+        assert_eq!(result.outcome_points, 6);
+        
+        let result = calculate_breakdown(&(Shape::Paper, 'X')).unwrap();
+        assert_eq!(result.total(), 1);
+    }
+
+    #[test]
+    fn calculate_breakdown2_works() {
+        let result = calculate_breakdown2(&(Shape::Rock, 'Y')).unwrap();
+        assert_eq!(result.total(), 4);
+        assert_eq!(result.my_shape, Shape::Rock);
+        
+        let result = calculate_breakdown2(&(Shape::Paper, 'X')).unwrap();
+        assert_eq!(result.total(), 1);
+    }
+
+    #[test]
+    fn parse_round_works() {
+        assert_eq!(parse_round("C Z"), Ok((Shape::Scissors, 'Z')));
+
+        assert_eq!(parse_round("Q Y"), Err(Error::invalid('Q', "a shape (A, B, C, X, Y or Z)")));
+        assert_eq!(parse_round("A B"), Err(Error::invalid('B', "X, Y or Z").at_column(2)));
+        assert!(parse_round("AY").is_err());
+        assert!(parse_round("A Y Z").is_err());
+    }
+
+    #[test]
+    fn parse_reports_line_of_error() {
+        let err = Day2::parse("A Y\nB X\nC W").unwrap_err();
+        assert_eq!(err.line(), Some(3));
+    }
+
+    #[test]
+    fn example_works() {
+        let rounds = Day2::parse("A Y\nB X\nC Z").unwrap();
+
+        assert_eq!(Day2::part1(&rounds), Ok(15));
+        assert_eq!(Day2::part2(&rounds), Ok(12));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use crate::Error;
+
+use super::game::OUTCOMES;
+use super::{rules, RoundScore, Shape};
+
+const COLUMNS: [&str; 7] = ["round", "opponent", "shape", "outcome", "shape points", "outcome points", "score"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Csv,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(Error::invalid(s, "a report format (csv or markdown)")),
+        }
+    }
+}
+
+/// Breaks down every round of the strategy guide, read with `read_round`.
+pub fn breakdown(rounds: &[(Shape, char)], read_round: impl Fn(&(Shape, char)) -> Result<RoundScore, Error>) -> Result<Vec<RoundScore>, Error> {
+    rounds.iter()
+        .enumerate()
+        .map(|(index, round)| read_round(round).map_err(|err| err.at_line(index + 1)))
+        .collect()
+}
+
+/// How often every outcome and shape occurs.
+#[derive(Debug, Default, PartialEq)]
+pub struct Histograms {
+    pub outcomes: [usize; 3],
+    pub elves_shapes: [usize; 3],
+    pub my_shapes: [usize; 3],
+}
+
+impl Histograms {
+    pub fn new(rounds: &[RoundScore]) -> Histograms {
+        let mut histograms = Histograms::default();
+
+        for round in rounds {
+            histograms.outcomes[OUTCOMES.iter().position(|outcome| *outcome == round.outcome).unwrap()] += 1;
+            histograms.elves_shapes[round.elves_shape.index()] += 1;
+            histograms.my_shapes[round.my_shape.index()] += 1;
+        }
+
+        histograms
+    }
+
+    /// One `(histogram, value, count)` row per bar.
+    fn rows(&self) -> Vec<[String; 3]> {
+        let outcomes = OUTCOMES.iter()
+                .zip(self.outcomes)
+                .map(|(outcome, count)| ["outcome".to_string(), format!("{:?}", outcome), count.to_string()]);
+
+        let elves_shapes = Shape::ALL.iter()
+                .zip(self.elves_shapes)
+                .map(|(shape, count)| ["opponent".to_string(), format!("{:?}", shape), count.to_string()]);
+
+        let my_shapes = Shape::ALL.iter()
+                .zip(self.my_shapes)
+                .map(|(shape, count)| ["shape".to_string(), format!("{:?}", shape), count.to_string()]);
+
+        outcomes.chain(elves_shapes).chain(my_shapes).collect()
+    }
+
+    pub fn format(&self, format: Format) -> String {
+        format_table(&["histogram", "value", "count"], &self.rows(), format)
+    }
+}
+
+fn row(number: usize, round: &RoundScore) -> [String; 7] {
+    [
+        number.to_string(),
+        format!("{:?}", round.elves_shape),
+        format!("{:?}", round.my_shape),
+        format!("{:?}", round.outcome),
+        round.shape_points.to_string(),
+        round.outcome_points.to_string(),
+        round.total().to_string(),
+    ]
+}
+
+/// Table of `(round number, round)` pairs, rounds are numbered from 1.
+pub fn format_rounds<'a>(rounds: impl IntoIterator<Item = (usize, &'a RoundScore)>, format: Format) -> String {
+    let rows: Vec<[String; 7]> = rounds.into_iter().map(|(number, round)| row(number, round)).collect();
+
+    format_table(&COLUMNS, &rows, format)
+}
+
+fn format_table<const N: usize>(header: &[&str; N], rows: &[[String; N]], format: Format) -> String {
+    let mut lines = Vec::with_capacity(rows.len() + 2);
+
+    match format {
+        Format::Csv => {
+            lines.push(header.join(","));
+            lines.extend(rows.iter().map(|row| row.join(",")));
+        },
+        Format::Markdown => {
+            lines.push(format!("| {} |", header.join(" | ")));
+            lines.push(format!("|{}", "---|".repeat(N)));
+            lines.extend(rows.iter().map(|row| format!("| {} |", row.join(" | "))));
+        },
+    }
+
+    lines.join("\n")
+}
+
+/// Parses a breakdown exported with `Format::Csv`, the first line is the header.
+pub fn parse_csv(input: &str) -> Result<Vec<RoundScore>, Error> {
+    let mut lines = input.lines();
+
+    match lines.next() {
+        Some(header) if header == COLUMNS.join(",") => {},
+        header => return Err(Error::invalid(header.unwrap_or(""), COLUMNS.join(",")).at_line(1)),
+    }
+
+    lines.enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| parse_csv_row(line).map_err(|err| err.at_line(index + 2)))
+        .collect()
+}
+
+fn parse_csv_row(line: &str) -> Result<RoundScore, Error> {
+    let fields: Vec<&str> = line.split(',').collect();
+
+    let [number, elves_shape, my_shape, outcome, shape_points, outcome_points, _] = fields[..] else {
+        return Err(Error::invalid(line, format!("{} comma separated columns", COLUMNS.len())));
+    };
+
+    number.parse::<usize>().map_err(|_| Error::invalid(number, "a round number"))?;
+
+    let points = |value: &str| value.parse::<u32>().map_err(|_| Error::invalid(value, "a number of points"));
+
+    Ok(RoundScore {
+        elves_shape: parse_shape(elves_shape)?,
+        my_shape: parse_shape(my_shape)?,
+        outcome: OUTCOMES.into_iter()
+                    .find(|candidate| format!("{:?}", candidate) == outcome)
+                    .ok_or_else(|| Error::invalid(outcome, "an outcome (Loss, Draw or Win)"))?,
+        shape_points: points(shape_points)?,
+        outcome_points: points(outcome_points)?,
+    })
+}
+
+fn parse_shape(name: &str) -> Result<Shape, Error> {
+    rules().find_shape(name)
+        .and_then(Shape::from_index)
+        .ok_or_else(|| Error::invalid(name, "a shape (Rock, Paper or Scissors)"))
+}
+
+/// Indices of the rounds which differ, including rounds only one of the breakdowns has.
+pub fn differing_rounds(ours: &[RoundScore], theirs: &[RoundScore]) -> Vec<usize> {
+    (0..ours.len().max(theirs.len()))
+        .filter(|index| ours.get(*index) != theirs.get(*index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day2::{calculate_breakdown, calculate_breakdown2, Outcome, EXAMPLE};
+
+    #[test]
+    fn breakdown_works() {
+        let rounds = breakdown(&EXAMPLE, calculate_breakdown2).unwrap();
+
+        assert_eq!(rounds[0], RoundScore { elves_shape: Shape::Rock, my_shape: Shape::Rock, outcome: Outcome::Draw, shape_points: 1, outcome_points: 3 });
+        assert_eq!(rounds.iter().map(RoundScore::total).sum::<u32>(), 12);
+
+        let err = breakdown(&[(Shape::Rock, 'X'), (Shape::Rock, 'Q')], calculate_breakdown).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+    }
+
+    #[test]
+    fn histograms_work() {
+        let histograms = Histograms::new(&breakdown(&EXAMPLE, calculate_breakdown).unwrap());
+
+        assert_eq!(histograms.outcomes, [1, 1, 1]);
+        assert_eq!(histograms.elves_shapes, [1, 1, 1]);
+        assert_eq!(histograms.my_shapes, [1, 1, 1]);
+
+        assert!(histograms.format(Format::Csv).starts_with("histogram,value,count\noutcome,Loss,1\n"));
+    }
+
+    #[test]
+    fn format_rounds_works() {
+        let rounds = breakdown(&EXAMPLE, calculate_breakdown).unwrap();
+
+        let csv = format_rounds(rounds.iter().enumerate().map(|(index, round)| (index + 1, round)), Format::Csv);
+        assert_eq!(csv.lines().nth(1), Some("1,Rock,Paper,Win,2,6,8"));
+
+        let markdown = format_rounds([(3, &rounds[2])], Format::Markdown);
+        assert_eq!(markdown, "| round | opponent | shape | outcome | shape points | outcome points | score |\n\
+                              |---|---|---|---|---|---|---|\n\
+                              | 3 | Scissors | Scissors | Draw | 3 | 3 | 6 |");
+    }
+
+    #[test]
+    fn csv_round_trip_works() {
+        let rounds = breakdown(&EXAMPLE, calculate_breakdown).unwrap();
+
+        let csv = format_rounds(rounds.iter().enumerate().map(|(index, round)| (index + 1, round)), Format::Csv);
+        assert_eq!(parse_csv(&csv), Ok(rounds));
+
+        let err = parse_csv(&csv.replace("2,Paper", "2,Lizard")).unwrap_err();
+        assert_eq!(err.line(), Some(3));
+
+        assert!(parse_csv("round,score\n1,8").is_err());
+    }
+
+    #[test]
+    fn differing_rounds_works() {
+        let ours = breakdown(&EXAMPLE, calculate_breakdown).unwrap();
+        let theirs = breakdown(&EXAMPLE, calculate_breakdown2).unwrap();
+
+        assert_eq!(differing_rounds(&ours, &theirs), vec![0, 2]);
+        assert_eq!(differing_rounds(&ours, &ours[..1]), vec![1, 2]);
+    }
+}
@@ -0,0 +1,226 @@
+use super::{calculate_breakdown2, rules, Outcome, RoundScore, Shape};
+
+/// A way of choosing my shape in every round of the tournament.
+pub trait Strategy {
+    fn name(&self) -> String;
+
+    /// Shape to play in round `round` (counted from 0), knowing the elf's shapes of all
+    /// previous rounds.
+    fn choose(&mut self, round: usize, elves_history: &[Shape]) -> Shape;
+}
+
+/// Shape beating `shape`.
+fn beater(shape: Shape) -> Shape {
+    rules().response(shape.index(), Outcome::Win)
+        .and_then(Shape::from_index)
+        .unwrap()
+}
+
+/// Always plays the same shape.
+pub struct Fixed(pub Shape);
+
+impl Strategy for Fixed {
+    fn name(&self) -> String {
+        format!("fixed {:?}", self.0)
+    }
+
+    fn choose(&mut self, _round: usize, _elves_history: &[Shape]) -> Shape {
+        self.0
+    }
+}
+
+/// Follows the strategy guide, its second column read as the outcome to reach.
+pub struct Guide {
+    choices: Vec<Shape>,
+}
+
+impl Guide {
+    pub fn new(rounds: &[(Shape, char)]) -> Guide {
+        let choices = rounds.iter()
+                .map(|round| calculate_breakdown2(round).map_or(round.0, |breakdown| breakdown.my_shape))
+                .collect();
+
+        Guide { choices }
+    }
+}
+
+impl Strategy for Guide {
+    fn name(&self) -> String {
+        "guide".to_string()
+    }
+
+    fn choose(&mut self, round: usize, _elves_history: &[Shape]) -> Shape {
+        self.choices.get(round).copied().unwrap_or(Shape::Rock)
+    }
+}
+
+/// Expects the elf to play the shape it played most often so far and beats it.
+#[derive(Default)]
+pub struct FrequencyCounting {
+    counts: [usize; 3],
+    seen: usize,
+}
+
+impl Strategy for FrequencyCounting {
+    fn name(&self) -> String {
+        "frequency".to_string()
+    }
+
+    fn choose(&mut self, _round: usize, elves_history: &[Shape]) -> Shape {
+        for shape in &elves_history[self.seen..] {
+            self.counts[shape.index()] += 1;
+        }
+        self.seen = elves_history.len();
+
+        beater(most_likely(&self.counts).unwrap_or(Shape::Rock))
+    }
+}
+
+/// Expects the elf to follow its last shape with the shape that most often followed it
+/// so far and beats it.
+#[derive(Default)]
+pub struct Markov {
+    transitions: [[usize; 3]; 3],
+    seen: usize,
+}
+
+impl Strategy for Markov {
+    fn name(&self) -> String {
+        "markov".to_string()
+    }
+
+    fn choose(&mut self, _round: usize, elves_history: &[Shape]) -> Shape {
+        for window in elves_history[self.seen.saturating_sub(1)..].windows(2) {
+            self.transitions[window[0].index()][window[1].index()] += 1;
+        }
+        self.seen = elves_history.len();
+
+        let prediction = elves_history.last()
+                .and_then(|last| most_likely(&self.transitions[last.index()]));
+
+        beater(prediction.unwrap_or(Shape::Rock))
+    }
+}
+
+/// Plays random shapes, the same seed gives the same shapes.
+pub struct Random {
+    seed: u64,
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        // xorshift gets stuck at 0
+        Random { seed, state: seed.max(1) }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+
+        self.state
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> String {
+        format!("random (seed {})", self.seed)
+    }
+
+    fn choose(&mut self, _round: usize, _elves_history: &[Shape]) -> Shape {
+        Shape::ALL[(self.next() % 3) as usize]
+    }
+}
+
+/// Shape with the highest count, `None` if nothing was counted.
+fn most_likely(counts: &[usize; 3]) -> Option<Shape> {
+    (0..3)
+        .filter(|index| counts[*index] > 0)
+        .max_by_key(|index| (counts[*index], std::cmp::Reverse(*index)))
+        .and_then(Shape::from_index)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+    pub strategy: String,
+    pub rounds: Vec<RoundScore>,
+}
+
+impl Simulation {
+    pub fn total_score(&self) -> u32 {
+        self.rounds.iter().map(RoundScore::total).sum()
+    }
+
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.rounds.iter().filter(|round| round.outcome == outcome).count()
+    }
+}
+
+/// Plays `strategy` against the elf's recorded shapes.
+pub fn simulate(elves_shapes: &[Shape], strategy: &mut dyn Strategy) -> Simulation {
+    let rounds = elves_shapes.iter()
+            .enumerate()
+            .map(|(round, &elves_shape)| {
+                let my_shape = strategy.choose(round, &elves_shapes[..round]);
+
+                RoundScore::new(elves_shape, my_shape)
+            })
+            .collect();
+
+    Simulation { strategy: strategy.name(), rounds }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day2::EXAMPLE;
+
+    fn elves_shapes(rounds: &[(Shape, char)]) -> Vec<Shape> {
+        rounds.iter().map(|(shape, _)| *shape).collect()
+    }
+
+    #[test]
+    fn guide_matches_part2() {
+        let simulation = simulate(&elves_shapes(&EXAMPLE), &mut Guide::new(&EXAMPLE));
+
+        assert_eq!(simulation.total_score(), 12);
+        assert_eq!(simulation.count(Outcome::Draw), 1);
+        assert_eq!(simulation.count(Outcome::Loss), 1);
+        assert_eq!(simulation.count(Outcome::Win), 1);
+    }
+
+    #[test]
+    fn fixed_works() {
+        let simulation = simulate(&elves_shapes(&EXAMPLE), &mut Fixed(Shape::Paper));
+
+        assert_eq!(simulation.rounds[0].outcome, Outcome::Win);
+        assert_eq!(simulation.rounds[2].outcome, Outcome::Loss);
+        assert_eq!(simulation.total_score(), 8 + 5 + 2);
+    }
+
+    #[test]
+    fn adaptive_strategies_beat_repetitive_elf() {
+        let elves_shapes = vec![Shape::Scissors; 10];
+
+        let frequency = simulate(&elves_shapes, &mut FrequencyCounting::default());
+        assert_eq!(frequency.count(Outcome::Win), 9);
+        assert_eq!(frequency.rounds[1].my_shape, Shape::Rock);
+
+        let cycle: Vec<Shape> = Shape::ALL.iter().cycle().take(12).copied().collect();
+
+        let markov = simulate(&cycle, &mut Markov::default());
+        assert!(markov.rounds[4..].iter().all(|round| round.outcome == Outcome::Win));
+    }
+
+    #[test]
+    fn random_is_reproducible() {
+        let elves_shapes = vec![Shape::Rock; 50];
+
+        let first = simulate(&elves_shapes, &mut Random::new(7));
+        let scnd = simulate(&elves_shapes, &mut Random::new(7));
+
+        assert_eq!(first, scnd);
+        assert!(first.count(Outcome::Win) > 0 && first.count(Outcome::Loss) > 0);
+    }
+}
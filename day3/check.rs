@@ -0,0 +1,129 @@
+use std::str::FromStr;
+
+use crate::Error;
+
+use super::item_set::ItemSet;
+use super::priority::PriorityScheme;
+use super::{chunk_groups, find_common_items, find_compartment_items, Rucksack};
+
+/// What to do about a rucksack or group which breaks the rules of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// fail on the first anomaly
+    Strict,
+    /// leave the rucksack or group out of the sum and carry on
+    Lenient,
+}
+
+impl FromStr for Mode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(Mode::Strict),
+            "lenient" => Ok(Mode::Lenient),
+            _ => Err(Error::invalid(s, "a mode (strict or lenient)")),
+        }
+    }
+}
+
+/// Sum of the priorities of the valid rucksacks or groups and the anomalies of all others,
+/// each with its line.
+#[derive(Debug, PartialEq)]
+pub struct Checked {
+    pub sum: u32,
+    pub anomalies: Vec<Error>,
+}
+
+impl Checked {
+    fn new() -> Checked {
+        Checked { sum: 0, anomalies: Vec::new() }
+    }
+
+    fn add(&mut self, priority: Result<u32, Error>, mode: Mode) -> Result<(), Error> {
+        match (priority, mode) {
+            (Ok(priority), _) => self.sum += priority,
+            (Err(err), Mode::Strict) => return Err(err),
+            (Err(err), Mode::Lenient) => self.anomalies.push(err),
+        }
+
+        Ok(())
+    }
+}
+
+/// Priority of the only item of `items`, fails if there are several.
+fn unique_priority(items: ItemSet, what: &str, scheme: &PriorityScheme) -> Result<u32, Error> {
+    match items.priorities().collect::<Vec<u32>>()[..] {
+        [priority] => Ok(priority),
+        _ => Err(Error::unsolvable(format!("{} {} instead of one: {}", items.len(), what, scheme.items(&items)))),
+    }
+}
+
+/// Sums the priorities of the one item packed in each of the `compartments` compartments
+/// of every rucksack.
+pub fn check_compartments(rucksacks: &[Rucksack], compartments: usize, scheme: &PriorityScheme, mode: Mode) -> Result<Checked, Error> {
+    let mut checked = Checked::new();
+
+    for (index, rucksack) in rucksacks.iter().enumerate() {
+        let priority = find_compartment_items(rucksack.items(), compartments, scheme)
+                .and_then(|items| unique_priority(items, "items are packed in all compartments", scheme))
+                .map_err(|err| err.at_line(index + 1));
+
+        checked.add(priority, mode)?;
+    }
+
+    Ok(checked)
+}
+
+/// Sums the priorities of the one badge item of every group of `group_size` consecutive
+/// rucksacks, an incomplete last group is an anomaly.
+pub fn check_groups(rucksacks: &[Rucksack], group_size: usize, scheme: &PriorityScheme, mode: Mode) -> Result<Checked, Error> {
+    let mut checked = Checked::new();
+
+    for (first_line, contents) in chunk_groups(rucksacks, group_size)? {
+        let priority = contents.and_then(|contents| {
+            find_common_items(&contents, scheme).and_then(|items| unique_priority(items, "badge items are common to the group", scheme))
+        });
+
+        checked.add(priority.map_err(|err| err.at_line(first_line)), mode)?;
+    }
+
+    Ok(checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day3::Day3;
+    use crate::Solution;
+
+    #[test]
+    fn check_compartments_works() {
+        let scheme = PriorityScheme::default();
+        let rucksacks = Day3::parse("abcb\nabc\nabab\nabcd\nvJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+
+        assert_eq!(check_compartments(&rucksacks, 2, &scheme, Mode::Strict), Err(Error::invalid("abc", "an even number of items").at_line(2)));
+
+        let checked = check_compartments(&rucksacks, 2, &scheme, Mode::Lenient).unwrap();
+        assert_eq!(checked.sum, 2 + 16);
+        assert_eq!(checked.anomalies.iter().map(|err| err.line()).collect::<Vec<_>>(), vec![Some(2), Some(3), Some(4)]);
+        assert_eq!(checked.anomalies[1], Error::unsolvable("2 items are packed in all compartments instead of one: ab").at_line(3));
+    }
+
+    #[test]
+    fn check_groups_works() {
+        let scheme = PriorityScheme::default();
+        let rucksacks = Day3::parse("abc\nbcd\ncde\nabc\nabd\nxyz\nab").unwrap();
+
+        assert_eq!(check_groups(&rucksacks, 3, &scheme, Mode::Strict), Err(Error::unsolvable("no badge item is common to the group").at_line(4)));
+
+        let checked = check_groups(&rucksacks, 3, &scheme, Mode::Lenient).unwrap();
+        assert_eq!(checked.sum, 3);
+        assert_eq!(checked.anomalies[1], Error::unsolvable("incomplete group of 1 rucksacks").at_line(7));
+
+        let checked = check_groups(&rucksacks[..2], 2, &scheme, Mode::Lenient).unwrap();
+        assert_eq!(checked.anomalies, vec![Error::unsolvable("2 badge items are common to the group instead of one: bc").at_line(1)]);
+
+        assert!(check_groups(&rucksacks, 0, &scheme, Mode::Lenient).is_err());
+    }
+}
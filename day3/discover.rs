@@ -0,0 +1,303 @@
+use crate::Error;
+
+use super::item_set::ItemSet;
+use super::priority::PriorityScheme;
+use super::Rucksack;
+
+/// Rucksacks found to form a group, by their lines, with the one badge they share.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub lines: Vec<usize>,
+    pub badge: char,
+}
+
+/// Whether the rucksacks can be grouped in only one way.
+#[derive(Debug, PartialEq)]
+pub enum Uniqueness {
+    Unique,
+    /// another way to group them
+    Ambiguous(Vec<Group>),
+    /// the search gave up before finding out
+    Unknown,
+}
+
+/// A partition of the rucksacks into groups and whether it is the only one.
+#[derive(Debug, PartialEq)]
+pub struct Discovery {
+    pub groups: Vec<Group>,
+    pub uniqueness: Uniqueness,
+}
+
+/// Exact cover search, every rucksack has to be in exactly one of the candidate groups.
+///
+/// The rucksack with the fewest candidates left is grouped first, a candidate is left as
+/// long as none of its rucksacks is grouped.
+struct Search {
+    candidates: Vec<Vec<usize>>,
+    /// candidates containing each rucksack
+    containing: Vec<Vec<usize>>,
+    /// how many rucksacks of each candidate are grouped
+    blocked: Vec<usize>,
+    /// how many candidates are left for each rucksack
+    left: Vec<usize>,
+    grouped: Vec<bool>,
+    chosen: Vec<usize>,
+    partitions: Vec<Vec<usize>>,
+    steps_left: usize,
+    /// whether a candidate was left untried for lack of steps
+    gave_up: bool,
+}
+
+impl Search {
+    fn new(candidates: Vec<Vec<usize>>, rucksack_count: usize, max_steps: usize) -> Search {
+        let mut containing = vec![Vec::new(); rucksack_count];
+
+        for (candidate, members) in candidates.iter().enumerate() {
+            for member in members {
+                containing[*member].push(candidate);
+            }
+        }
+
+        Search {
+            blocked: vec![0; candidates.len()],
+            left: containing.iter().map(Vec::len).collect(),
+            grouped: vec![false; rucksack_count],
+            containing,
+            candidates,
+            chosen: Vec::new(),
+            partitions: Vec::new(),
+            steps_left: max_steps,
+            gave_up: false,
+        }
+    }
+
+    /// Marks the rucksacks of `candidate` as grouped (`grouped == true`) or takes it back.
+    fn set_grouped(&mut self, candidate: usize, grouped: bool) {
+        for &member in &self.candidates[candidate] {
+            self.grouped[member] = grouped;
+
+            for &other in &self.containing[member] {
+                let was_left = self.blocked[other] == 0;
+
+                if grouped {
+                    self.blocked[other] += 1;
+                } else {
+                    self.blocked[other] -= 1;
+                }
+
+                if was_left != (self.blocked[other] == 0) {
+                    for &other_member in &self.candidates[other] {
+                        if grouped {
+                            self.left[other_member] -= 1;
+                        } else {
+                            self.left[other_member] += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether the search is done, which is after finding two partitions or
+    /// running out of steps.
+    fn search(&mut self) -> bool {
+        let rucksack = (0..self.grouped.len())
+                .filter(|rucksack| !self.grouped[*rucksack])
+                .min_by_key(|rucksack| self.left[*rucksack]);
+
+        let Some(rucksack) = rucksack else {
+            self.partitions.push(self.chosen.clone());
+
+            return self.partitions.len() == 2;
+        };
+
+        let options: Vec<usize> = self.containing[rucksack].iter()
+                .copied()
+                .filter(|candidate| self.blocked[*candidate] == 0)
+                .collect();
+
+        for candidate in options {
+            if self.steps_left == 0 {
+                self.gave_up = true;
+
+                return true;
+            }
+            self.steps_left -= 1;
+
+            self.set_grouped(candidate, true);
+            self.chosen.push(candidate);
+
+            let done = self.search();
+
+            self.chosen.pop();
+            self.set_grouped(candidate, false);
+
+            if done {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Every group of `group_size` of the `sets` sharing exactly one item, with that item.
+fn candidate_groups(sets: &[ItemSet], group_size: usize) -> Vec<(Vec<usize>, ItemSet)> {
+    fn extend(sets: &[ItemSet], group_size: usize, members: &mut Vec<usize>, common: ItemSet, found: &mut Vec<(Vec<usize>, ItemSet)>) {
+        if members.len() == group_size {
+            if common.len() == 1 {
+                found.push((members.clone(), common));
+            }
+
+            return;
+        }
+
+        let start = members.last().map_or(0, |last| last + 1);
+
+        for candidate in start..sets.len() {
+            let candidate_common = if members.is_empty() { sets[candidate] } else { common.intersection(&sets[candidate]) };
+
+            if !candidate_common.is_empty() {
+                members.push(candidate);
+                extend(sets, group_size, members, candidate_common, found);
+                members.pop();
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+
+    extend(sets, group_size, &mut Vec::new(), ItemSet::new(), &mut found);
+
+    found
+}
+
+/// Partitions rucksacks in any order into groups of `group_size`, in which the rucksacks
+/// share exactly one item, their badge.
+///
+/// Finding the groups is an exact cover problem, the search gives up after `max_steps`
+/// tried groups.
+pub fn discover_groups(rucksacks: &[Rucksack], group_size: usize, scheme: &PriorityScheme, max_steps: usize) -> Result<Discovery, Error> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return Err(Error::invalid(group_size.to_string(), format!("a group size dividing the number of rucksacks {}", rucksacks.len())));
+    }
+
+    let sets = rucksacks.iter()
+            .enumerate()
+            .map(|(index, rucksack)| scheme.item_set(rucksack.items().chars()).map_err(|err| err.at_line(index + 1)))
+            .collect::<Result<Vec<ItemSet>, Error>>()?;
+
+    let (members, badges): (Vec<Vec<usize>>, Vec<ItemSet>) = candidate_groups(&sets, group_size).into_iter().unzip();
+
+    let mut search = Search::new(members, sets.len(), max_steps);
+
+    search.search();
+
+    let mut partitions = search.partitions.iter().map(|partition| {
+        let mut groups: Vec<Group> = partition.iter()
+                .map(|candidate| Group {
+                    lines: search.candidates[*candidate].iter().map(|index| index + 1).collect(),
+                    badge: scheme.items(&badges[*candidate]).chars().next().unwrap(),
+                })
+                .collect();
+
+        groups.sort_by_key(|group| group.lines[0]);
+
+        groups
+    });
+
+    let groups = match (partitions.next(), search.gave_up) {
+        (Some(groups), _) => groups,
+        (None, false) => return Err(Error::unsolvable(format!("no partition into groups of {} with one badge each", group_size))),
+        (None, true) => return Err(Error::unsolvable(format!("no partition into groups of {} found within {} steps", group_size, max_steps))),
+    };
+
+    let uniqueness = match (partitions.next(), search.gave_up) {
+        (Some(alternative), _) => Uniqueness::Ambiguous(alternative),
+        (None, false) => Uniqueness::Unique,
+        (None, true) => Uniqueness::Unknown,
+    };
+
+    Ok(Discovery { groups, uniqueness })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day3::Day3;
+    use crate::Solution;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    fn both_partitions(discovery: Discovery) -> Vec<Vec<Group>> {
+        let Uniqueness::Ambiguous(alternative) = discovery.uniqueness else {
+            panic!("expected an ambiguous partition, got {:?}", discovery.uniqueness);
+        };
+
+        let mut partitions = vec![discovery.groups, alternative];
+        partitions.sort_by_key(|groups| groups[0].badge);
+
+        partitions
+    }
+
+    #[test]
+    fn discover_groups_works() {
+        let scheme = PriorityScheme::default();
+        let mut rucksacks = Day3::parse(EXAMPLE).unwrap();
+
+        // the example lines in the order 1, 4, 2, 5, 3, 6
+        let shuffled = vec![rucksacks[0].clone(), rucksacks[3].clone(), rucksacks[1].clone(), rucksacks[4].clone(), rucksacks[2].clone(), rucksacks[5].clone()];
+
+        let discovery = discover_groups(&shuffled, 3, &scheme, 1000).unwrap();
+
+        // even the example can be grouped in another way
+        assert_eq!(both_partitions(discovery), vec![
+            vec![Group { lines: vec![1, 4, 6], badge: 'J' }, Group { lines: vec![2, 3, 5], badge: 'q' }],
+            vec![Group { lines: vec![1, 3, 5], badge: 'r' }, Group { lines: vec![2, 4, 6], badge: 'Z' }],
+        ]);
+
+        rucksacks.truncate(3);
+        assert_eq!(discover_groups(&rucksacks, 1, &scheme, 1000).unwrap_err(), Error::unsolvable("no partition into groups of 1 with one badge each"));
+    }
+
+    #[test]
+    fn uniqueness_works() {
+        let scheme = PriorityScheme::default();
+        let rucksacks = Day3::parse("ab\nac\nbd\ncd").unwrap();
+
+        assert_eq!(both_partitions(discover_groups(&rucksacks, 2, &scheme, 1000).unwrap()), vec![
+            vec![Group { lines: vec![1, 2], badge: 'a' }, Group { lines: vec![3, 4], badge: 'd' }],
+            vec![Group { lines: vec![1, 3], badge: 'b' }, Group { lines: vec![2, 4], badge: 'c' }],
+        ]);
+
+        let rucksacks = Day3::parse("ab\ncd\nbx\ndy").unwrap();
+        let discovery = discover_groups(&rucksacks, 2, &scheme, 1000).unwrap();
+        assert_eq!(discovery.groups, vec![Group { lines: vec![1, 3], badge: 'b' }, Group { lines: vec![2, 4], badge: 'd' }]);
+        assert_eq!(discovery.uniqueness, Uniqueness::Unique);
+
+        assert!(discover_groups(&rucksacks, 3, &scheme, 1000).is_err());
+    }
+
+    #[test]
+    fn search_gives_up() {
+        let scheme = PriorityScheme::default();
+        let rucksacks = Day3::parse("ab\nac\nbd\ncd").unwrap();
+
+        let discovery = discover_groups(&rucksacks, 2, &scheme, 2).unwrap();
+        assert_eq!(discovery.uniqueness, Uniqueness::Unknown);
+
+        assert!(discover_groups(&rucksacks, 2, &scheme, 1).is_err());
+
+        // the search finishing on its last step did not give up
+        let rucksacks = Day3::parse("ab\ncd\nbx\ndy").unwrap();
+        let discovery = discover_groups(&rucksacks, 2, &scheme, 2).unwrap();
+        assert_eq!(discovery.uniqueness, Uniqueness::Unique);
+
+        assert_eq!(discover_groups(&rucksacks, 4, &scheme, 0).unwrap_err(), Error::unsolvable("no partition into groups of 4 with one badge each"));
+    }
+}
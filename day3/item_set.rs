@@ -0,0 +1,105 @@
+/// Set of item priorities, priority `p` is stored in bit `p`.
+///
+/// `PriorityScheme::item_set` builds the set of some items, `PriorityScheme::items` turns
+/// it back into items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> ItemSet {
+        ItemSet(0)
+    }
+
+    /// Adds `priority`, which has to be at most `MAX_PRIORITY`.
+    pub fn insert(&mut self, priority: u32) {
+        self.0 |= 1 << priority;
+    }
+
+    pub fn contains(&self, priority: u32) -> bool {
+        priority < u64::BITS && self.0 & 1 << priority != 0
+    }
+
+    pub fn union(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn difference(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities in ascending order.
+    pub fn priorities(&self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+
+            let priority = bits.trailing_zeros();
+            bits &= bits - 1;
+
+            Some(priority)
+        })
+    }
+}
+
+impl FromIterator<u32> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(priorities: I) -> ItemSet {
+        let mut set = ItemSet::new();
+
+        for priority in priorities {
+            set.insert(priority);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_works() {
+        let mut set = ItemSet::new();
+        set.insert(1);
+        set.insert(52);
+        set.insert(1);
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(1) && set.contains(52));
+        assert!(!set.contains(2));
+        assert!(!set.contains(100));
+    }
+
+    #[test]
+    fn set_operations_work() {
+        let first: ItemSet = [1, 2, 3, 50].into_iter().collect();
+        let scnd: ItemSet = [2, 3, 4, 51].into_iter().collect();
+
+        assert_eq!(first.union(&scnd).priorities().collect::<Vec<u32>>(), vec![1, 2, 3, 4, 50, 51]);
+        assert_eq!(first.intersection(&scnd).priorities().collect::<Vec<u32>>(), vec![2, 3]);
+        assert_eq!(first.difference(&scnd).priorities().collect::<Vec<u32>>(), vec![1, 50]);
+        assert!(first.intersection(&ItemSet::new()).is_empty());
+    }
+
+    #[test]
+    fn priorities_work() {
+        let set: ItemSet = [38, 1, 16, 63].into_iter().collect();
+
+        assert_eq!(set.priorities().collect::<Vec<u32>>(), vec![1, 16, 38, 63]);
+    }
+}
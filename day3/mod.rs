@@ -0,0 +1,241 @@
+use std::str::FromStr;
+
+use crate::{parse_lines, Error, Solution};
+
+pub mod check;
+pub mod discover;
+pub mod item_set;
+pub mod priority;
+
+use check::{check_compartments, check_groups, Mode};
+use item_set::ItemSet;
+use priority::PriorityScheme;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLE_FILE: Option<&'static str> = Some("input_example.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, Rucksack::from_str)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<u32, Error> {
+        check_compartments(rucksacks, 2, &PriorityScheme::default(), Mode::Strict).map(|checked| checked.sum)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<u32, Error> {
+        check_groups(rucksacks, 3, &PriorityScheme::default(), Mode::Strict).map(|checked| checked.sum)
+    }
+}
+
+/// Groups of `group_size` consecutive rucksacks with the line of their first rucksack, an
+/// incomplete last group is an error at its first line.
+pub fn chunk_groups(rucksacks: &[Rucksack], group_size: usize) -> Result<impl Iterator<Item = (usize, Result<Vec<&str>, Error>)>, Error> {
+    if group_size == 0 {
+        return Err(Error::invalid("0", "a group size of at least 1"));
+    }
+
+    Ok(rucksacks.chunks(group_size)
+        .enumerate()
+        .map(move |(group_no, group)| {
+            let first_line = group_no * group_size + 1;
+
+            let contents = if group.len() < group_size {
+                Err(Error::unsolvable(format!("incomplete group of {} rucksacks", group.len())).at_line(first_line))
+            } else {
+                Ok(group.iter().map(Rucksack::items).collect())
+            };
+
+            (first_line, contents)
+        }))
+}
+
+/// Like `chunk_groups`, but fails on an incomplete last group before yielding any group.
+pub fn groups(rucksacks: &[Rucksack], group_size: usize) -> Result<impl Iterator<Item = (usize, Vec<&str>)>, Error> {
+    let groups = chunk_groups(rucksacks, group_size)?
+            .map(|(first_line, contents)| contents.map(|contents| (first_line, contents)))
+            .collect::<Result<Vec<(usize, Vec<&str>)>, Error>>()?;
+
+    Ok(groups.into_iter())
+}
+
+/// Content of a rucksack, every item has a priority in the scheme it was parsed with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rucksack(String);
+
+impl Rucksack {
+    pub fn parse_with(content: &str, scheme: &PriorityScheme) -> Result<Rucksack, Error> {
+        scheme.item_set(content.chars())?;
+
+        Ok(Rucksack(content.to_string()))
+    }
+
+    pub fn items(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = Error;
+
+    /// Parses a rucksack of the puzzle, every item is one of a-z or A-Z.
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        Rucksack::parse_with(content, &PriorityScheme::default())
+    }
+}
+
+/// Items packed in every one of the `compartments` equally sized compartments of a
+/// rucksack.
+pub fn find_compartment_items(content: &str, compartments: usize, scheme: &PriorityScheme) -> Result<ItemSet, Error> {
+    let items: Vec<char> = content.chars().collect();
+    let item_number = items.len();
+
+    if compartments == 0 {
+        return Err(Error::invalid("0", "at least 1 compartment"));
+    }
+
+    if !item_number.is_multiple_of(compartments) {
+        let expected = match compartments {
+            2 => "an even number of items".to_string(),
+            _ => format!("a number of items divisible by {}", compartments),
+        };
+
+        return Err(Error::invalid(content, expected));
+    }
+
+    let compartment_size = item_number / compartments;
+
+    let mut common = scheme.item_set(items[..compartment_size].iter().copied())?;
+
+    for (compartment_no, compartment) in items.chunks(compartment_size.max(1)).enumerate().skip(1) {
+        let compartment = scheme.item_set(compartment.iter().copied())
+                .map_err(|err| err.at_column(compartment_no * compartment_size))?;
+
+        common = common.intersection(&compartment);
+    }
+
+    if common.is_empty() {
+        return Err(Error::unsolvable(format!("no item is packed in all {} compartments", compartments)));
+    }
+
+    Ok(common)
+}
+
+/// Items every one of the rucksacks `contents` contains.
+pub fn find_common_items(contents: &[&str], scheme: &PriorityScheme) -> Result<ItemSet, Error> {
+    let Some((first, others)) = contents.split_first() else {
+        return Err(Error::invalid("no rucksacks", "a group of at least 1 rucksack"));
+    };
+
+    let mut common = scheme.item_set(first.chars())?;
+
+    for content in others {
+        common = common.intersection(&scheme.item_set(content.chars())?);
+    }
+
+    if common.is_empty() {
+        return Err(Error::unsolvable("no badge item is common to the group"));
+    }
+
+    Ok(common)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn find_compartment_items_works() {
+        let scheme = PriorityScheme::default();
+
+        const INPUT_STR: &str = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let result = find_compartment_items(INPUT_STR, 2, &scheme).unwrap();
+        assert_eq!(scheme.items(&result), "p");
+
+        const INPUT_STR2: &str = "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL";
+        let result = find_compartment_items(INPUT_STR2, 2, &scheme).unwrap();
+        assert_eq!(scheme.items(&result), "L");
+
+        let result = find_compartment_items("abcbcaxbc", 3, &scheme).unwrap();
+        assert_eq!(scheme.items(&result), "bc");
+    }
+
+    #[test]
+    fn find_compartment_items_reports_errors() {
+        let scheme = PriorityScheme::default();
+
+        assert_eq!(find_compartment_items("abcab", 2, &scheme), Err(Error::invalid("abcab", "an even number of items")));
+        assert_eq!(find_compartment_items("abcd", 3, &scheme), Err(Error::invalid("abcd", "a number of items divisible by 3")));
+        assert!(matches!(find_compartment_items("abcd", 2, &scheme), Err(Error::Unsolvable { .. })));
+        assert!(find_compartment_items("abcd", 0, &scheme).is_err());
+        assert_eq!(find_compartment_items("ab1d", 2, &scheme), Err(Error::invalid('1', "an item (a-z or A-Z)").at_column(2)));
+    }
+
+    #[test]
+    fn custom_priority_scheme_works() {
+        let scheme = PriorityScheme::from_table("1 1\n2 2\nä 3\nö 4").unwrap();
+
+        let rucksack = Rucksack::parse_with("1äöä22", &scheme).unwrap();
+        let result = find_compartment_items(rucksack.items(), 2, &scheme).unwrap();
+        assert_eq!(scheme.items(&result), "ä");
+
+        let result = find_common_items(&["12ä", "ö2ä", "ä2"], &scheme).unwrap();
+        assert_eq!(result.priorities().sum::<u32>(), 2 + 3);
+
+        assert!(Rucksack::parse_with("1a", &scheme).is_err());
+    }
+
+    #[test]
+    fn find_common_items_works() {
+        const INPUT_GROUP: [&str; 3] = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg"
+        ];
+
+        let scheme = PriorityScheme::default();
+
+        let result = find_common_items(&INPUT_GROUP, &scheme).unwrap();
+        assert_eq!(scheme.items(&result), "r");
+
+        let result = find_common_items(&["abcd", "bcde", "xbcy", "cb"], &scheme).unwrap();
+        assert_eq!(scheme.items(&result), "bc");
+
+        assert!(find_common_items(&[], &scheme).is_err());
+        assert!(matches!(find_common_items(&["ab", "cd"], &scheme), Err(Error::Unsolvable { .. })));
+    }
+
+    #[test]
+    fn groups_works() {
+        let rucksacks = Day3::parse("abcd\nbcde\nxbcy\ncb").unwrap();
+
+        let pairs: Vec<(usize, Vec<&str>)> = groups(&rucksacks, 2).unwrap().collect();
+        assert_eq!(pairs, vec![(1, vec!["abcd", "bcde"]), (3, vec!["xbcy", "cb"])]);
+
+        assert_eq!(groups(&rucksacks, 3).err(), Some(Error::unsolvable("incomplete group of 1 rucksacks").at_line(4)));
+        assert!(groups(&rucksacks, 0).is_err());
+
+        let contents: Vec<(usize, Result<Vec<&str>, Error>)> = chunk_groups(&rucksacks, 3).unwrap().collect();
+        assert_eq!(contents[1], (4, Err(Error::unsolvable("incomplete group of 1 rucksacks").at_line(4))));
+    }
+
+    #[test]
+    fn rucksack_from_str_works() {
+        assert_eq!(Rucksack::from_str("abCD").unwrap().items(), "abCD");
+        assert_eq!(Rucksack::from_str("ab1d"), Err(Error::invalid('1', "an item (a-z or A-Z)").at_column(2)));
+    }
+
+    #[test]
+    fn part2_reports_incomplete_group() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nabcd";
+
+        let err = Day3::part2(&Day3::parse(input).unwrap()).unwrap_err();
+        assert_eq!(err, Error::unsolvable("incomplete group of 1 rucksacks").at_line(4));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use crate::Error;
+
+use super::item_set::ItemSet;
+
+/// Highest priority an `ItemSet` can hold.
+pub const MAX_PRIORITY: u32 = 63;
+
+/// Which priority every item has.
+///
+/// ASCII items are looked up in a table, all other items in a map. Priorities are unique
+/// and at most `MAX_PRIORITY`, as they double as the bits of an `ItemSet`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriorityScheme {
+    /// priority of every ASCII item, 0 for items without one
+    ascii: [u8; 128],
+    other: HashMap<char, u8>,
+    items: [Option<char>; MAX_PRIORITY as usize + 1],
+    /// what to expect instead of an unknown item
+    expected: String,
+}
+
+impl Default for PriorityScheme {
+    /// Priorities of the puzzle, a-z are 1 to 26 and A-Z are 27 to 52.
+    fn default() -> PriorityScheme {
+        let pairs = ('a'..='z').chain('A'..='Z').zip(1..);
+
+        let mut scheme = PriorityScheme::from_pairs(pairs).unwrap();
+        scheme.expected = "an item (a-z or A-Z)".to_string();
+
+        scheme
+    }
+}
+
+impl PriorityScheme {
+    /// Builds the scheme from `(item, priority)` pairs.
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (char, u32)>) -> Result<PriorityScheme, Error> {
+        let mut scheme = PriorityScheme::empty();
+
+        for (item, priority) in pairs {
+            scheme.insert(item, priority)?;
+        }
+
+        Ok(scheme.described())
+    }
+
+    /// Parses a table with one item and its priority per line, like `ä 53`. Empty lines and
+    /// lines starting with `#` are skipped.
+    pub fn from_table(table: &str) -> Result<PriorityScheme, Error> {
+        let mut scheme = PriorityScheme::empty();
+
+        for (index, line) in table.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            parse_pair(line)
+                .and_then(|(item, priority)| scheme.insert(item, priority))
+                .map_err(|err| err.at_line(index + 1))?;
+        }
+
+        Ok(scheme.described())
+    }
+
+    fn empty() -> PriorityScheme {
+        PriorityScheme { ascii: [0; 128], other: HashMap::new(), items: [None; MAX_PRIORITY as usize + 1], expected: String::new() }
+    }
+
+    fn described(mut self) -> PriorityScheme {
+        let count = self.items.iter().flatten().count();
+
+        self.expected = format!("one of the {} items of the priority scheme", count);
+
+        self
+    }
+
+    fn insert(&mut self, item: char, priority: u32) -> Result<(), Error> {
+        if priority == 0 || priority > MAX_PRIORITY {
+            return Err(Error::invalid(priority.to_string(), format!("a priority from 1 to {}", MAX_PRIORITY)));
+        }
+
+        if let Some(other_item) = self.items[priority as usize] {
+            return Err(Error::invalid(priority.to_string(), format!("a priority not already given to '{}'", other_item)));
+        }
+
+        if self.lookup(item) != 0 {
+            return Err(Error::invalid(item, "an item listed only once"));
+        }
+
+        self.items[priority as usize] = Some(item);
+
+        match u8::try_from(item) {
+            Ok(ascii) if ascii.is_ascii() => self.ascii[ascii as usize] = priority as u8,
+            _ => { self.other.insert(item, priority as u8); },
+        }
+
+        Ok(())
+    }
+
+    /// Priority of `item`, 0 if it has none.
+    fn lookup(&self, item: char) -> u8 {
+        match u8::try_from(item) {
+            Ok(ascii) if ascii.is_ascii() => self.ascii[ascii as usize],
+            _ => self.other.get(&item).copied().unwrap_or(0),
+        }
+    }
+
+    pub fn priority(&self, item: char) -> Result<u32, Error> {
+        match self.lookup(item) {
+            0 => Err(Error::invalid(item, self.expected.as_str())),
+            priority => Ok(priority as u32),
+        }
+    }
+
+    pub fn item(&self, priority: u32) -> Option<char> {
+        self.items.get(priority as usize).copied().flatten()
+    }
+
+    /// Set of all `items`, errors name the column of the unknown item.
+    pub fn item_set(&self, items: impl IntoIterator<Item = char>) -> Result<ItemSet, Error> {
+        let mut set = ItemSet::new();
+
+        for (index, item) in items.into_iter().enumerate() {
+            set.insert(self.priority(item).map_err(|err| err.at_column(index))?);
+        }
+
+        Ok(set)
+    }
+
+    /// Items of `set` ordered by priority.
+    pub fn items(&self, set: &ItemSet) -> String {
+        set.priorities().filter_map(|priority| self.item(priority)).collect()
+    }
+}
+
+fn parse_pair(line: &str) -> Result<(char, u32), Error> {
+    let mut chars = line.chars();
+
+    let (Some(item), Some(' ')) = (chars.next(), chars.next()) else {
+        return Err(Error::invalid(line, "an item and its priority like 'a 1'"));
+    };
+
+    let priority = chars.as_str()
+            .trim()
+            .parse()
+            .map_err(|_| Error::invalid(chars.as_str(), "a priority").at_column(2))?;
+
+    Ok((item, priority))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_works() {
+        let scheme = PriorityScheme::default();
+
+        assert_eq!(scheme.priority('b'), Ok(2));
+        assert_eq!(scheme.priority('B'), Ok(28));
+        assert_eq!(scheme.priority('Z'), Ok(52));
+        assert_eq!(scheme.priority('7'), Err(Error::invalid('7', "an item (a-z or A-Z)")));
+
+        for item in ('a'..='z').chain('A'..='Z') {
+            assert_eq!(scheme.item(scheme.priority(item).unwrap()), Some(item));
+        }
+
+        assert_eq!(scheme.item(0), None);
+        assert_eq!(scheme.item(53), None);
+    }
+
+    #[test]
+    fn from_table_works() {
+        let scheme = PriorityScheme::from_table("# digits and umlauts\n0 1\n9 10\n\nä 11\nß 63").unwrap();
+
+        assert_eq!(scheme.priority('9'), Ok(10));
+        assert_eq!(scheme.priority('ä'), Ok(11));
+        assert_eq!(scheme.item(63), Some('ß'));
+        assert_eq!(scheme.priority('a'), Err(Error::invalid('a', "one of the 4 items of the priority scheme")));
+
+        let set = scheme.item_set("ß0ä0".chars()).unwrap();
+        assert_eq!(scheme.items(&set), "0äß");
+    }
+
+    #[test]
+    fn from_table_reports_errors() {
+        assert_eq!(PriorityScheme::from_table("a 1\nb x").unwrap_err().line(), Some(2));
+        assert_eq!(PriorityScheme::from_table("a 1\n\nb 64").unwrap_err(), Error::invalid("64", "a priority from 1 to 63").at_line(3));
+        assert_eq!(PriorityScheme::from_table("a 1\nb 1").unwrap_err(), Error::invalid("1", "a priority not already given to 'a'").at_line(2));
+        assert_eq!(PriorityScheme::from_table("a 1\na 2").unwrap_err(), Error::invalid('a', "an item listed only once").at_line(2));
+        assert!(PriorityScheme::from_table("ab 1").is_err());
+    }
+
+    #[test]
+    fn item_set_reports_column() {
+        let scheme = PriorityScheme::default();
+
+        assert_eq!(scheme.item_set("ab1".chars()), Err(Error::invalid('1', "an item (a-z or A-Z)").at_column(2)));
+    }
+}
@@ -0,0 +1,197 @@
+use crate::interval::{Interval, Wording};
+use crate::interval_set::{Coverage, IntervalSet};
+use crate::{parse_lines, Error, Solution};
+
+pub mod overlaps;
+pub mod plan;
+pub mod render;
+
+/// Sections assigned to an elf, both ids included.
+pub type SectionRange = Interval<u64>;
+
+/// Parses a range like `2-4`.
+pub fn parse_section_range(input: &str) -> Result<SectionRange, Error> {
+    const WORDING: Wording = Wording {
+        format: "a section range like '2-4'",
+        interval: "a range",
+        bound: "a section id",
+    };
+
+    Interval::parse_closed(input, &WORDING)
+}
+
+/// Sections assigned to the elves of a cleanup team, in the order they are listed.
+pub type Team = Vec<SectionRange>;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Team>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLE_FILE: Option<&'static str> = Some("input_example.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, parse_team)
+    }
+
+    fn part1(teams: &Self::Input) -> Result<usize, Error> {
+        Ok(teams.iter()
+            .filter(|team| contains_fully_contained_range(team))
+            .count())
+    }
+
+    fn part2(teams: &Self::Input) -> Result<usize, Error> {
+        Ok(teams.iter()
+            .filter(|team| has_common_section(team))
+            .count())
+    }
+}
+
+/// Parses a team of any number of elves like `2-4,6-8,3-5`.
+pub fn parse_team(line: &str) -> Result<Team, Error> {
+    let mut column = 0;
+
+    line.split(',')
+        .map(|range_str| {
+            let range = parse_section_range(range_str).map_err(|err| err.at_column(column));
+            column += range_str.len() + 1;
+
+            range
+        })
+        .collect()
+}
+
+/// Formats a team like the input, `2-4,6-8`.
+pub fn format_team(team: &[SectionRange]) -> String {
+    let ranges: Vec<String> = team.iter()
+            .map(|range| {
+                let end = if range.is_closed() { *range.end() } else { range.end() - 1 };
+
+                format!("{}-{}", range.start(), end)
+            })
+            .collect();
+
+    ranges.join(",")
+}
+
+/// How many elves of the whole camp are assigned to each section.
+pub fn camp_coverage(teams: &[Team]) -> Coverage<u64> {
+    Coverage::new(teams.iter().flatten().copied())
+}
+
+/// Smallest range containing every assignment, `None` if there are none.
+pub fn camp_span(teams: &[Team]) -> Option<SectionRange> {
+    let ranges = teams.iter().flatten();
+
+    let start = ranges.clone().map(|range| *range.start()).min()?;
+    let end = ranges.map(|range| *range.end()).max()?;
+
+    Some(Interval::closed(start, end))
+}
+
+/// Positions in `team` of the elves whose sections are all assigned to other elves of the
+/// team as well, each on its own, so of two equal ranges both are redundant.
+pub fn redundant_ranges(team: &[SectionRange]) -> Vec<usize> {
+    (0..team.len())
+        .filter(|position| {
+            let others = IntervalSet::from_intervals(team.iter()
+                .enumerate()
+                .filter(|(other, _)| other != position)
+                .map(|(_, range)| *range));
+
+            others.gaps(&team[*position]).is_empty()
+        })
+        .collect()
+}
+
+fn contains_fully_contained_range(team: &[SectionRange]) -> bool {
+    team.iter().enumerate().any(|(position, range)| {
+        team.iter()
+            .enumerate()
+            .any(|(other, other_range)| other != position && range.is_subset_of(other_range))
+    })
+}
+
+fn has_common_section(team: &[SectionRange]) -> bool {
+    team.iter()
+        .skip(1)
+        .try_fold(team[0], |common, range| common.intersection(range))
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_section_range_works() {
+        let range = parse_section_range("5-6").unwrap();
+
+        assert_eq!(*range.start(), 5);
+        assert_eq!(*range.end(), 6);
+        assert_eq!(range.len(), 2);
+
+        assert!(parse_section_range("6-5").is_err());
+        assert_eq!(parse_section_range("56").err(), Some(Error::invalid("56", "a section range like '2-4'")));
+        assert_eq!(parse_section_range("[5, 6]").err(), Some(Error::invalid("[5, 6]", "a section range like '2-4'")));
+        assert_eq!(parse_section_range("5-x").err(), Some(Error::invalid("x", "a section id").at_column(2)));
+    }
+
+    #[test]
+    fn contains_fully_contained_range_works() {
+        let res = contains_fully_contained_range(&parse_team("6-6,4-8").unwrap());
+        assert!(res);
+
+        let res = contains_fully_contained_range(&parse_team("6-6,4-5").unwrap());
+        assert!(!res);
+
+        let res = contains_fully_contained_range(&parse_team("1-3,6-6,4-5,5-9").unwrap());
+        assert!(res);
+    }
+
+    #[test]
+    fn parse_team_reports_column() {
+        let err = parse_team("6-6,4-y").unwrap_err();
+        assert_eq!(err, Error::invalid("y", "a section id").at_column(6));
+
+        let err = parse_team("6-6,4-5,2-1").unwrap_err();
+        assert_eq!(err, Error::invalid("2-1", "a range that does not end before it starts").at_column(8));
+
+        assert_eq!(parse_team("6-6").unwrap(), vec![Interval::closed(6, 6)]);
+        assert_eq!(format_team(&parse_team("1-2,3-4,5-6").unwrap()), "1-2,3-4,5-6");
+    }
+
+    #[test]
+    fn has_common_section_works() {
+        assert!(has_common_section(&parse_team("5-7,7-9").unwrap()));
+        assert!(has_common_section(&parse_team("2-8,3-7").unwrap()));
+        assert!(has_common_section(&parse_team("2-8,3-7,7-9").unwrap()));
+        assert!(!has_common_section(&parse_team("2-8,3-6,7-9").unwrap()));
+        assert!(has_common_section(&parse_team("2-4").unwrap()));
+    }
+
+    #[test]
+    fn redundant_ranges_works() {
+        assert_eq!(redundant_ranges(&parse_team("2-4,3-6,5-8,2-9").unwrap()), vec![0, 1, 2]);
+        assert_eq!(redundant_ranges(&parse_team("2-4,3-6,5-8,2-8").unwrap()), vec![0, 1, 2, 3]);
+        assert_eq!(redundant_ranges(&parse_team("2-4,2-4,5-5").unwrap()), vec![0, 1]);
+        assert!(redundant_ranges(&parse_team("2-4,4-6").unwrap()).is_empty());
+        assert!(redundant_ranges(&parse_team("2-4").unwrap()).is_empty());
+    }
+
+    #[test]
+    fn camp_coverage_works() {
+        let teams = Day4::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8").unwrap();
+        let coverage = camp_coverage(&teams);
+
+        assert_eq!(camp_span(&teams), Some(Interval::closed(2, 9)));
+        assert_eq!(coverage.covered().len(), 8);
+        assert_eq!(coverage.at_least(5).runs(), &[Interval::closed(3, 7)]);
+        assert_eq!(coverage.max_depth(), Some((8, Interval::closed(6, 6))));
+
+        assert_eq!(camp_span(&[]), None);
+    }
+
+}
\ No newline at end of file
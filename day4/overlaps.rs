@@ -0,0 +1,125 @@
+use crate::interval_set::overlapping_pairs;
+
+use super::{SectionRange, Team};
+
+/// An elf by the line of its team and its position in the team, starting at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Elf {
+    pub line: usize,
+    pub position: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// the assignments share sections, but neither contains the other
+    Overlap,
+    /// the assignment of the first elf contains the one of the second elf
+    Containment,
+}
+
+/// Two elves whose assignments share sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hit {
+    pub first: Elf,
+    pub scnd: Elf,
+    pub relation: Relation,
+}
+
+impl Hit {
+    /// Whether both elves are of the same team, which is what the puzzle looks at.
+    pub fn is_within_team(&self) -> bool {
+        self.first.line == self.scnd.line
+    }
+}
+
+/// Every elf of the camp with its assignment, ordered by line and position.
+pub fn elves(teams: &[Team]) -> Vec<(Elf, SectionRange)> {
+    teams.iter()
+        .enumerate()
+        .flat_map(|(index, team)| team.iter()
+            .enumerate()
+            .map(move |(position, range)| (Elf { line: index + 1, position: position + 1 }, *range)))
+        .collect()
+}
+
+/// Every two elves of the camp whose assignments share sections, ordered by their elves.
+///
+/// A containment names the elf with the larger assignment first, for equal assignments
+/// the one listed first.
+pub fn find_overlaps(teams: &[Team]) -> Vec<Hit> {
+    let elves = elves(teams);
+
+    let ranges: Vec<SectionRange> = elves.iter().map(|(_, range)| *range).collect();
+
+    let mut hits: Vec<Hit> = overlapping_pairs(&ranges).into_iter()
+            .map(|(index1, index2)| {
+                let (first, scnd) = (index1.min(index2), index1.max(index2));
+                let ((first_elf, first_range), (scnd_elf, scnd_range)) = (elves[first], elves[scnd]);
+
+                if scnd_range.is_subset_of(&first_range) {
+                    Hit { first: first_elf, scnd: scnd_elf, relation: Relation::Containment }
+                } else if first_range.is_subset_of(&scnd_range) {
+                    Hit { first: scnd_elf, scnd: first_elf, relation: Relation::Containment }
+                } else {
+                    Hit { first: first_elf, scnd: scnd_elf, relation: Relation::Overlap }
+                }
+            })
+            .collect();
+
+    hits.sort_by_key(|hit| (hit.first.min(hit.scnd), hit.first.max(hit.scnd)));
+
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day4::Day4;
+    use crate::Solution;
+
+    const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    fn elf(line: usize, position: usize) -> Elf {
+        Elf { line, position }
+    }
+
+    #[test]
+    fn find_overlaps_works() {
+        let teams = Day4::parse("2-4,6-8\n3-3,4-6\n4-6,9-9").unwrap();
+
+        assert_eq!(find_overlaps(&teams), vec![
+            Hit { first: elf(1, 1), scnd: elf(2, 1), relation: Relation::Containment },
+            Hit { first: elf(1, 1), scnd: elf(2, 2), relation: Relation::Overlap },
+            Hit { first: elf(1, 1), scnd: elf(3, 1), relation: Relation::Overlap },
+            Hit { first: elf(1, 2), scnd: elf(2, 2), relation: Relation::Overlap },
+            Hit { first: elf(1, 2), scnd: elf(3, 1), relation: Relation::Overlap },
+            Hit { first: elf(2, 2), scnd: elf(3, 1), relation: Relation::Containment },
+        ]);
+    }
+
+    #[test]
+    fn find_overlaps_numbers_team_members() {
+        let teams = Day4::parse("1-2,3-4,2-3\n5-6,6-7").unwrap();
+
+        assert_eq!(find_overlaps(&teams), vec![
+            Hit { first: elf(1, 1), scnd: elf(1, 3), relation: Relation::Overlap },
+            Hit { first: elf(1, 2), scnd: elf(1, 3), relation: Relation::Overlap },
+            Hit { first: elf(2, 1), scnd: elf(2, 2), relation: Relation::Overlap },
+        ]);
+    }
+
+    #[test]
+    fn find_overlaps_agrees_with_puzzle() {
+        let teams = Day4::parse(EXAMPLE).unwrap();
+
+        let hits: Vec<Hit> = find_overlaps(&teams).into_iter().filter(Hit::is_within_team).collect();
+
+        assert_eq!(hits.len(), Day4::part2(&teams).unwrap());
+        assert_eq!(hits.iter().filter(|hit| hit.relation == Relation::Containment).count(), Day4::part1(&teams).unwrap());
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::VecDeque;
+
+use crate::interval::Interval;
+use crate::Error;
+
+use super::overlaps::{elves, Elf};
+use super::{SectionRange, Team};
+
+/// New assignments which cover a span without overlapping.
+#[derive(Debug, PartialEq)]
+pub struct Plan {
+    pub teams: Vec<Team>,
+    /// elves with a new assignment, ordered by line and position
+    pub changed: Vec<Elf>,
+}
+
+/// How many elves can be kept up to the end of a kept elf, and where that came from,
+/// `None` for the start of the span.
+#[derive(Debug, Clone, Copy)]
+struct Reached {
+    kept: usize,
+    before: Option<(usize, usize)>,
+}
+
+/// States reached at the end of a kept elf, by the number of elves used up to there in
+/// ascending order, only the reachable ones are stored.
+type Row = Vec<(usize, Reached)>;
+
+fn reach(target: &mut Option<Reached>, kept: usize, before: Option<(usize, usize)>) {
+    if target.is_none_or(|reached| reached.kept < kept) {
+        *target = Some(Reached { kept, before });
+    }
+}
+
+/// Reaches the end of a kept elf from `from`, the elves used up to the end of an earlier
+/// kept elf (or the start of the span), with 1 to `gap` new elves in the sections between.
+fn reach_across_gap(from: &Row, gap: u64, before: impl Fn(usize) -> Option<(usize, usize)>, target: &mut [Option<Reached>]) {
+    let mut window: VecDeque<(usize, Reached)> = VecDeque::new();
+    let mut next = from.iter().peekable();
+
+    for (used, target) in target.iter_mut().enumerate().skip(2) {
+        if let Some(&(from_used, reached)) = next.next_if(|(from_used, _)| *from_used == used - 2) {
+            while window.back().is_some_and(|(_, back)| back.kept <= reached.kept) {
+                window.pop_back();
+            }
+
+            window.push_back((from_used, reached));
+        }
+
+        while window.front().is_some_and(|(front, _)| (used - 1 - front) as u64 > gap) {
+            window.pop_front();
+        }
+
+        match window.front() {
+            Some(&(best_used, best)) => reach(target, best.kept + 1, before(best_used)),
+            None if next.peek().is_none() => break,
+            None => {},
+        }
+    }
+}
+
+/// Splits `sections` into `count` runs as even as possible.
+fn split(start: u64, sections: u64, count: usize) -> Vec<SectionRange> {
+    let count = count as u64;
+
+    (0..count)
+        .scan(start, |next, index| {
+            let len = sections / count + u64::from(index < sections % count);
+            let range = Interval::closed(*next, *next + len - 1);
+            *next += len;
+
+            Some(range)
+        })
+        .collect()
+}
+
+/// Reassigns as few elves as possible, such that every section of `span` is assigned to
+/// exactly one elf.
+///
+/// The kept elves are found by dynamic programming over the kept elf ending last and the
+/// number of elves assigned up to its end, which takes O(n³) time for n elves and O(n²)
+/// memory for the reachable states. The other elves fill the gaps left to right, in the
+/// order of their old assignments.
+pub fn plan_reallocation(teams: &[Team], span: &SectionRange) -> Result<Plan, Error> {
+    let elves = elves(teams);
+
+    let count = elves.len();
+    let sections = span.len();
+
+    if count == 0 || count as u64 > sections {
+        return Err(Error::unsolvable(format!("{} elves cannot share the {} sections of {}", count, sections, span)));
+    }
+
+    let first = *span.start();
+    let last = first + (sections - 1);
+
+    let mut candidates: Vec<usize> = (0..count)
+            .filter(|elf| elves[*elf].1.is_subset_of(span))
+            .collect();
+    candidates.sort_by_key(|elf| (*elves[*elf].1.start(), *elf));
+
+    let range = |candidate: usize| elves[candidates[candidate]].1;
+
+    // reached[k]: the states reached at the end of candidate k, which is kept
+    let mut reached: Vec<Row> = Vec::with_capacity(candidates.len());
+
+    let start: Row = vec![(0, Reached { kept: 0, before: None })];
+    let mut target: Vec<Option<Reached>> = vec![None; count + 1];
+
+    for k in 0..candidates.len() {
+        target.fill(None);
+
+        let gap = *range(k).start() - first;
+
+        if gap == 0 {
+            reach(&mut target[1], 1, None);
+        } else {
+            reach_across_gap(&start, gap, |_| None, &mut target);
+        }
+
+        for (j, from) in reached.iter().enumerate() {
+            if range(j).end() >= range(k).start() {
+                continue;
+            }
+
+            let gap = *range(k).start() - *range(j).end() - 1;
+
+            if gap == 0 {
+                for &(used, state) in from.iter().filter(|(used, _)| *used < count) {
+                    reach(&mut target[used + 1], state.kept + 1, Some((j, used)));
+                }
+            } else {
+                reach_across_gap(from, gap, |used| Some((j, used)), &mut target);
+            }
+        }
+
+        reached.push(target.iter()
+            .enumerate()
+            .filter_map(|(used, state)| state.map(|state| (used, state)))
+            .collect());
+    }
+
+    // the new elves have to fit after the last kept elf as well
+    let mut best: (usize, Option<(usize, usize)>) = (0, None);
+
+    for (j, row) in reached.iter().enumerate() {
+        let gap = last - *range(j).end();
+
+        for &(used, state) in row {
+            let left = (count - used) as u64;
+
+            if (gap == 0 && left == 0 || gap > 0 && left > 0 && left <= gap) && state.kept > best.0 {
+                best = (state.kept, Some((j, used)));
+            }
+        }
+    }
+
+    // kept candidates with the number of elves used up to their end, first to last
+    let mut chain: Vec<(usize, usize)> = Vec::new();
+    let mut current = best.1;
+
+    while let Some((j, used)) = current {
+        chain.push((j, used));
+        let row = &reached[j];
+
+        current = row[row.binary_search_by_key(&used, |(row_used, _)| *row_used).unwrap()].1.before;
+    }
+
+    chain.reverse();
+
+    let mut kept = vec![false; count];
+    for (j, _) in &chain {
+        kept[candidates[*j]] = true;
+    }
+
+    let mut moved: Vec<usize> = (0..count).filter(|elf| !kept[*elf]).collect();
+    moved.sort_by_key(|elf| (*elves[*elf].1.start(), *elf));
+
+    // the sections between the kept elves and how many new elves they get
+    let mut gaps: Vec<(u64, u64, usize)> = Vec::new();
+    let mut next = first;
+    let mut used_before = 0;
+
+    for (j, used) in &chain {
+        if *range(*j).start() > next {
+            gaps.push((next, *range(*j).start() - next, used - used_before - 1));
+        }
+
+        next = *range(*j).end() + 1;
+        used_before = *used;
+    }
+
+    if next <= last {
+        gaps.push((next, last - next + 1, count - used_before));
+    }
+
+    let mut new_teams = teams.to_vec();
+    let new_ranges = gaps.iter().flat_map(|(start, sections, count)| split(*start, *sections, *count));
+
+    for (elf, new_range) in moved.iter().zip(new_ranges) {
+        let Elf { line, position } = elves[*elf].0;
+
+        new_teams[line - 1][position - 1] = new_range;
+    }
+
+    let mut changed: Vec<Elf> = moved.iter()
+            .filter(|elf| {
+                let Elf { line, position } = elves[**elf].0;
+
+                new_teams[line - 1][position - 1] != elves[**elf].1
+            })
+            .map(|elf| elves[*elf].0)
+            .collect();
+    changed.sort();
+
+    Ok(Plan { teams: new_teams, changed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day4::{camp_coverage, Day4};
+    use crate::Solution;
+
+    fn assert_covers(plan: &Plan, span: &SectionRange) {
+        let coverage = camp_coverage(&plan.teams);
+
+        assert_eq!(coverage.covered().runs(), &[*span]);
+        assert_eq!(coverage.max_depth().unwrap().0, 1);
+    }
+
+    #[test]
+    fn plan_reallocation_works() {
+        let teams = Day4::parse("1-3,4-6\n4-5,7-9").unwrap();
+        let span = Interval::closed(1, 9);
+
+        let plan = plan_reallocation(&teams, &span).unwrap();
+
+        assert_eq!(plan.teams, Day4::parse("1-3,6-6\n4-5,7-9").unwrap());
+        assert_eq!(plan.changed, vec![Elf { line: 1, position: 2 }]);
+    }
+
+    /// Most elves which can keep their assignment, by trying every subset.
+    fn most_kept(ranges: &[SectionRange], span: &SectionRange) -> usize {
+        (0..1usize << ranges.len())
+            .filter(|subset| {
+                let mut kept: Vec<SectionRange> = (0..ranges.len())
+                        .filter(|elf| subset & 1 << elf != 0)
+                        .map(|elf| ranges[elf])
+                        .collect();
+                kept.sort();
+
+                let mut gap_starts = vec![*span.start()];
+                gap_starts.extend(kept.iter().map(|range| range.end() + 1));
+                let mut gap_ends: Vec<u64> = kept.iter().map(|range| *range.start()).collect();
+                gap_ends.push(span.end() + 1);
+
+                let gaps: Vec<i64> = gap_starts.iter().zip(&gap_ends).map(|(start, end)| *end as i64 - *start as i64).collect();
+                let left = (ranges.len() - kept.len()) as i64;
+
+                kept.iter().all(|range| range.is_subset_of(span))
+                    && gaps.iter().all(|gap| *gap >= 0)
+                    && gaps.iter().filter(|gap| **gap > 0).count() as i64 <= left
+                    && gaps.iter().sum::<i64>() >= left
+            })
+            .map(|subset| subset.count_ones() as usize)
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn plan_reallocation_keeps_most_elves() {
+        let teams = Day4::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n6-6,4-6").unwrap();
+        let ranges: Vec<SectionRange> = teams.iter().flatten().copied().collect();
+
+        for (start, end) in [(1, 9), (2, 9), (1, 8), (1, 30), (4, 12), (2, 10), (5, 12), (3, 10)] {
+            let span = Interval::closed(start, end);
+            let plan = plan_reallocation(&teams, &span).unwrap();
+
+            assert_covers(&plan, &span);
+            assert_eq!(ranges.len() - plan.changed.len(), most_kept(&ranges, &span), "{}", span);
+        }
+
+        let plan = plan_reallocation(&Day4::parse("1-1,2-2").unwrap(), &Interval::closed(1, 2)).unwrap();
+        assert!(plan.changed.is_empty());
+    }
+
+    #[test]
+    fn plan_reallocation_reports_infeasibility() {
+        let teams = Day4::parse("1-3,4-6\n4-5,7-9").unwrap();
+
+        let err = plan_reallocation(&teams, &Interval::closed(1, 3)).unwrap_err();
+        assert_eq!(err, Error::unsolvable("4 elves cannot share the 3 sections of [1, 3]"));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::interval::Interval;
+use crate::interval_set::Coverage;
+
+use super::{format_team, SectionRange};
+
+/// How the sections of a bound map to the columns of a strip, every column shows the
+/// same number of sections.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scale {
+    first: u64,
+    last: u64,
+    sections_per_column: u64,
+}
+
+impl Scale {
+    /// Fits the sections of `bounds` into at most `max_columns` columns.
+    pub fn new(bounds: &SectionRange, max_columns: usize) -> Scale {
+        let first = *bounds.start();
+        let last = first + bounds.len().max(1) - 1;
+        let sections_per_column = bounds.len().div_ceil(max_columns.max(1) as u64).max(1);
+
+        Scale { first, last, sections_per_column }
+    }
+
+    pub fn columns(&self) -> usize {
+        (self.last - self.first + 1).div_ceil(self.sections_per_column) as usize
+    }
+
+    /// Sections shown in `column`.
+    fn sections(&self, column: usize) -> SectionRange {
+        let start = self.first + column as u64 * self.sections_per_column;
+
+        Interval::closed(start, (start + self.sections_per_column - 1).min(self.last))
+    }
+}
+
+/// Draws one column of the strip of `range`, the last digit of the section like the
+/// puzzle does, or at a smaller scale `=` if the range covers all sections of the
+/// column and `-` if it covers some of them.
+fn cell(range: &SectionRange, scale: &Scale, column: usize) -> char {
+    let sections = scale.sections(column);
+
+    if !range.overlaps(&sections) {
+        '.'
+    } else if scale.sections_per_column == 1 {
+        char::from_digit((sections.start() % 10) as u32, 10).unwrap()
+    } else if sections.is_subset_of(range) {
+        '='
+    } else {
+        '-'
+    }
+}
+
+/// Notes on the elves of `team` whose range contains another one or is contained in one.
+fn containment_notes(team: &[SectionRange], position: usize) -> String {
+    let elves = |filter: &dyn Fn(&SectionRange) -> bool| -> Vec<String> {
+        (0..team.len())
+            .filter(|other| *other != position && filter(&team[*other]))
+            .map(|other| (other + 1).to_string())
+            .collect()
+    };
+
+    let contains = elves(&|other| other.is_subset_of(&team[position]));
+    let inside = elves(&|other| team[position].is_subset_of(other));
+
+    let mut notes = Vec::new();
+
+    if !contains.is_empty() {
+        notes.push(format!("contains elf {}", contains.join(", ")));
+    }
+    if !inside.is_empty() {
+        notes.push(format!("inside elf {}", inside.join(", ")));
+    }
+
+    notes.join(", ")
+}
+
+/// Draws a team as aligned strips like `.234.....  2-4`, one for every elf, with a line
+/// marking the columns with sections assigned to more than one elf by `^`.
+pub fn render_team(team: &[SectionRange], scale: &Scale) -> Vec<String> {
+    let labels: Vec<String> = team.iter().map(|range| format_team(&[*range])).collect();
+    let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+
+    let mut lines: Vec<String> = team.iter()
+            .enumerate()
+            .map(|(position, range)| {
+                let strip: String = (0..scale.columns()).map(|column| cell(range, scale, column)).collect();
+
+                let line = format!("{}  {:<width$}  {}", strip, labels[position], containment_notes(team, position), width = label_width);
+
+                line.trim_end().to_string()
+            })
+            .collect();
+
+    let overlaps = Coverage::new(team.iter().copied()).at_least(2);
+
+    if !overlaps.is_empty() {
+        let marks: String = (0..scale.columns())
+                .map(|column| if overlaps.runs().iter().any(|run| run.overlaps(&scale.sections(column))) { '^' } else { ' ' })
+                .collect();
+
+        lines.push(marks.trim_end().to_string());
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day4::parse_team;
+
+    fn render(line: &str, bounds: SectionRange, max_columns: usize) -> String {
+        render_team(&parse_team(line).unwrap(), &Scale::new(&bounds, max_columns)).join("\n")
+    }
+
+    #[test]
+    fn render_team_works() {
+        assert_eq!(render("2-4,6-8", Interval::closed(1, 9), 80), "\
+.234.....  2-4
+.....678.  6-8");
+
+        assert_eq!(render("5-7,7-9", Interval::closed(1, 9), 80), "\
+....567..  5-7
+......789  7-9
+      ^");
+
+        assert_eq!(render("2-8,3-7,12-12", Interval::closed(1, 12), 80), "\
+.2345678....  2-8    contains elf 2
+..34567.....  3-7    inside elf 1
+...........2  12-12
+  ^^^^^");
+    }
+
+    #[test]
+    fn render_team_scales() {
+        let scale = Scale::new(&Interval::closed(1, 99), 40);
+        assert_eq!(scale.columns(), 33);
+
+        assert_eq!(render("1-10,9-30", Interval::closed(1, 40), 10), "\
+==-.......  1-10
+..=====-..  9-30
+  ^");
+
+        assert_eq!(render("1-4,5-5", Interval::closed(1, 5), 4), "\
+==.  1-4
+..=  5-5");
+    }
+}
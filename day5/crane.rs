@@ -0,0 +1,281 @@
+use std::str::FromStr;
+
+use crate::Error;
+
+use super::{Move, MoveError, Stack};
+
+/// Crates taken from the top of one stack and put on top of another in one go.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lift {
+    pub number_of_crates: usize,
+    pub origin: usize,
+    pub target: usize,
+    /// whether the crates land in reverse order
+    pub flipped: bool,
+}
+
+impl Lift {
+    /// The lift putting the crates back.
+    pub fn inverse(&self) -> Lift {
+        Lift { origin: self.target, target: self.origin, ..*self }
+    }
+
+    pub fn apply(&self, stacks: &mut [Stack]) {
+        let origin = &mut stacks[self.origin - 1];
+        let split_index = origin.len().saturating_sub(self.number_of_crates);
+
+        let mut lifted = origin.split_off(split_index);
+
+        if self.flipped {
+            lifted.reverse();
+        }
+
+        stacks[self.target - 1].append(&mut lifted);
+    }
+}
+
+/// A model of crane carrying out the moves of the rearrangement procedure.
+pub trait Crane {
+    fn name(&self) -> String;
+
+    /// Lifts carrying out `amove`, in order.
+    fn lifts(&mut self, amove: &Move) -> Vec<Lift>;
+
+    /// How many moves from the start of `moves` are carried out in the same step.
+    fn moves_per_step(&self, _moves: &[Move]) -> usize {
+        1
+    }
+}
+
+fn lift(amove: &Move, number_of_crates: usize, flipped: bool) -> Lift {
+    Lift { number_of_crates, origin: amove.origin, target: amove.target, flipped }
+}
+
+/// Lifts one crate at a time, part 1 of the puzzle.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "9000".to_string()
+    }
+
+    fn lifts(&mut self, amove: &Move) -> Vec<Lift> {
+        vec![lift(amove, 1, false); amove.number_of_crates]
+    }
+}
+
+/// Lifts all crates of a move at once, part 2 of the puzzle.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "9001".to_string()
+    }
+
+    fn lifts(&mut self, amove: &Move) -> Vec<Lift> {
+        vec![lift(amove, amove.number_of_crates, false)]
+    }
+}
+
+/// Lifts at most `capacity` crates at once, keeping their order.
+pub struct Capped {
+    pub capacity: usize,
+}
+
+impl Crane for Capped {
+    fn name(&self) -> String {
+        format!("capacity {}", self.capacity)
+    }
+
+    fn lifts(&mut self, amove: &Move) -> Vec<Lift> {
+        let capacity = self.capacity.max(1);
+
+        (0..amove.number_of_crates)
+            .step_by(capacity)
+            .map(|lifted| lift(amove, capacity.min(amove.number_of_crates - lifted), false))
+            .collect()
+    }
+}
+
+/// Lifts all crates of a move at once, but every other lift lands in reverse order.
+#[derive(Default)]
+pub struct Flipping {
+    lifts: usize,
+}
+
+impl Crane for Flipping {
+    fn name(&self) -> String {
+        "flipping".to_string()
+    }
+
+    fn lifts(&mut self, amove: &Move) -> Vec<Lift> {
+        self.lifts += 1;
+
+        vec![lift(amove, amove.number_of_crates, self.lifts.is_multiple_of(2))]
+    }
+}
+
+/// Two arms working like `arm`, which carry out two moves in the same step when they
+/// touch different stacks.
+pub struct TwoArm {
+    pub arm: Box<dyn Crane>,
+}
+
+impl Crane for TwoArm {
+    fn name(&self) -> String {
+        format!("two-arm {}", self.arm.name())
+    }
+
+    fn lifts(&mut self, amove: &Move) -> Vec<Lift> {
+        self.arm.lifts(amove)
+    }
+
+    fn moves_per_step(&self, moves: &[Move]) -> usize {
+        match moves {
+            [first, scnd, ..] if ![scnd.origin, scnd.target].iter().any(|stack| *stack == first.origin || *stack == first.target) => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// What to do about a move which cannot be carried out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// stop before the move
+    Abort,
+    /// leave the move out and carry on
+    Skip,
+}
+
+impl FromStr for Mode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "abort" => Ok(Mode::Abort),
+            "skip" => Ok(Mode::Skip),
+            _ => Err(Error::invalid(s, "a mode (abort or skip)")),
+        }
+    }
+}
+
+/// How much work a crane did and the moves it left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub lifts: usize,
+    pub steps: usize,
+    pub skipped: Vec<MoveError>,
+}
+
+/// Carries out `moves` on `stacks` with `crane`, every move is validated right before.
+///
+/// When aborting, `stacks` are left as they were after the last valid move.
+pub fn operate(crane: &mut dyn Crane, moves: &[Move], stacks: &mut [Stack], mode: Mode) -> Result<Operation, MoveError> {
+    operate_observed(crane, moves, stacks, mode, |_, _| ())
+}
+
+/// Like `operate`, but calls `observe` with the index and the lifts of every move after
+/// carrying it out, a skipped move has no lifts.
+pub fn operate_observed(crane: &mut dyn Crane, moves: &[Move], stacks: &mut [Stack], mode: Mode, mut observe: impl FnMut(usize, &[Lift])) -> Result<Operation, MoveError> {
+    let mut operation = Operation { lifts: 0, steps: 0, skipped: Vec::new() };
+    let mut done = 0;
+
+    while done < moves.len() {
+        let step_moves = crane.moves_per_step(&moves[done..]).clamp(1, moves.len() - done);
+
+        for (index, amove) in moves.iter().enumerate().skip(done).take(step_moves) {
+            if let Err(err) = amove.validate(stacks) {
+                match mode {
+                    Mode::Abort => return Err(err),
+                    Mode::Skip => {
+                        operation.skipped.push(err);
+                        observe(index, &[]);
+                        continue;
+                    },
+                }
+            }
+
+            let lifts = crane.lifts(amove);
+
+            for lift in &lifts {
+                lift.apply(stacks);
+            }
+
+            operation.lifts += lifts.len();
+            observe(index, &lifts);
+        }
+
+        done += step_moves;
+        operation.steps += 1;
+    }
+
+    Ok(operation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day5::{top_crates, Day5, MoveErrorKind};
+    use crate::Solution;
+
+    fn top_crates_with(crane: &mut dyn Crane) -> (String, Operation) {
+        let input_str = std::fs::read_to_string("day5/input_example.txt").unwrap();
+        let (mut stacks, moves) = Day5::parse(&input_str).unwrap();
+
+        let operation = operate(crane, &moves, &mut stacks, Mode::Abort).unwrap();
+
+        (top_crates(&stacks).unwrap(), operation)
+    }
+
+    #[test]
+    fn lift_works() {
+        let mut stacks = vec![vec!['A', 'B', 'C'], vec!['D']];
+
+        Lift { number_of_crates: 2, origin: 1, target: 2, flipped: true }.apply(&mut stacks);
+        assert_eq!(stacks, vec![vec!['A'], vec!['D', 'C', 'B']]);
+
+        Lift { number_of_crates: 2, origin: 2, target: 1, flipped: false }.apply(&mut stacks);
+        assert_eq!(stacks, vec![vec!['A', 'C', 'B'], vec!['D']]);
+    }
+
+    #[test]
+    fn cranes_work() {
+        assert_eq!(top_crates_with(&mut CrateMover9000), ("CMZ".to_string(), Operation { lifts: 7, steps: 4, skipped: Vec::new() }));
+        assert_eq!(top_crates_with(&mut CrateMover9001), ("MCD".to_string(), Operation { lifts: 4, steps: 4, skipped: Vec::new() }));
+        assert_eq!(top_crates_with(&mut Capped { capacity: 1 }).0, "CMZ");
+        assert_eq!(top_crates_with(&mut Capped { capacity: 2 }), ("MCZ".to_string(), Operation { lifts: 5, steps: 4, skipped: Vec::new() }));
+        assert_eq!(top_crates_with(&mut Flipping::default()), ("MCZ".to_string(), Operation { lifts: 4, steps: 4, skipped: Vec::new() }));
+    }
+
+    #[test]
+    fn operate_aborts_or_skips() {
+        let moves: Vec<Move> = ["move 1 from 1 to 2", "move 2 from 1 to 2", "move 1 from 2 to 1"].into_iter()
+                .map(|line| Move::try_from(line).unwrap())
+                .collect();
+
+        let mut stacks = vec![vec!['A', 'B'], vec![]];
+        let err = operate(&mut CrateMover9000, &moves, &mut stacks, Mode::Abort).unwrap_err();
+        assert_eq!(err.kind, MoveErrorKind::InsufficientCrates { stack: 1, needed: 2, available: 1 });
+        assert_eq!(stacks, vec![vec!['A'], vec!['B']]);
+
+        let mut stacks = vec![vec!['A', 'B'], vec![]];
+        let operation = operate(&mut CrateMover9000, &moves, &mut stacks, Mode::Skip).unwrap();
+        assert_eq!(operation.skipped, vec![err]);
+        assert_eq!(operation.lifts, 2);
+        assert_eq!(stacks, vec![vec!['A', 'B'], vec![]]);
+    }
+
+    #[test]
+    fn two_arm_works() {
+        let mut crane = TwoArm { arm: Box::new(CrateMover9001) };
+        assert_eq!(top_crates_with(&mut crane), ("MCD".to_string(), Operation { lifts: 4, steps: 4, skipped: Vec::new() }));
+
+        let mut stacks = vec![vec!['A'], vec![], vec!['B'], vec![]];
+        let moves: Vec<Move> = ["move 1 from 1 to 2", "move 1 from 3 to 4", "move 1 from 4 to 1"].into_iter()
+                .map(|line| Move::try_from(line).unwrap())
+                .collect();
+
+        assert_eq!(operate(&mut crane, &moves, &mut stacks, Mode::Abort).unwrap(), Operation { lifts: 3, steps: 2, skipped: Vec::new() });
+        assert_eq!(stacks, vec![vec!['B'], vec!['A'], vec![], vec![]]);
+    }
+}
@@ -0,0 +1,158 @@
+use super::crane::{operate_observed, Crane, Lift, Mode, Operation};
+use super::{Move, MoveError, Stack};
+
+/// Every state of the stacks during the rearrangement procedure.
+///
+/// Only the current stacks are kept, with the lifts of every move as deltas, so going to
+/// another move replays or takes back the lifts in between.
+#[derive(Debug, Clone)]
+pub struct History {
+    stacks: Vec<Stack>,
+    /// moves carried out on `stacks`
+    position: usize,
+    lifts: Vec<Lift>,
+    /// lifts of move `i` are `lifts[starts[i]..starts[i + 1]]`
+    starts: Vec<usize>,
+    operation: Operation,
+}
+
+impl History {
+    /// Carries out `moves` with `crane`, the history then is at the end.
+    pub fn record(crane: &mut dyn Crane, stacks: &[Stack], moves: &[Move], mode: Mode) -> Result<History, MoveError> {
+        let mut final_stacks = stacks.to_vec();
+        let mut lifts = Vec::new();
+        let mut starts = vec![0];
+
+        let operation = operate_observed(crane, moves, &mut final_stacks, mode, |_, move_lifts| {
+            lifts.extend_from_slice(move_lifts);
+            starts.push(lifts.len());
+        })?;
+
+        Ok(History { stacks: final_stacks, position: moves.len(), lifts, starts, operation })
+    }
+
+    /// Number of moves recorded.
+    pub fn len(&self) -> usize {
+        self.starts.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of moves carried out on the current stacks.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn stacks(&self) -> &[Stack] {
+        &self.stacks
+    }
+
+    pub fn operation(&self) -> &Operation {
+        &self.operation
+    }
+
+    /// Lifts of move `index` (counted from 0), none if it was skipped.
+    pub fn lifts(&self, index: usize) -> &[Lift] {
+        &self.lifts[self.starts[index]..self.starts[index + 1]]
+    }
+
+    /// Carries out the next move, returns `false` at the end.
+    pub fn step_forward(&mut self) -> bool {
+        if self.position == self.len() {
+            return false;
+        }
+
+        for lift in &self.lifts[self.starts[self.position]..self.starts[self.position + 1]] {
+            lift.apply(&mut self.stacks);
+        }
+
+        self.position += 1;
+
+        true
+    }
+
+    /// Takes back the last move, returns `false` at the start.
+    pub fn step_back(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+
+        self.position -= 1;
+
+        for lift in self.lifts[self.starts[self.position]..self.starts[self.position + 1]].iter().rev() {
+            lift.inverse().apply(&mut self.stacks);
+        }
+
+        true
+    }
+
+    /// Goes to the state after `position` moves, or to the end if there are fewer.
+    pub fn jump_to(&mut self, position: usize) {
+        let position = position.min(self.len());
+
+        while self.position < position {
+            self.step_forward();
+        }
+        while self.position > position {
+            self.step_back();
+        }
+    }
+
+    /// Crate on top of `stack` (counted from 1) after `position` moves, `None` if the
+    /// stack is empty or does not exist.
+    pub fn top_after(&mut self, position: usize, stack: usize) -> Option<char> {
+        self.jump_to(position);
+
+        self.stacks.get(stack.checked_sub(1)?)?.last().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day5::crane::{operate, CrateMover9000, CrateMover9001};
+    use crate::day5::Day5;
+    use crate::Solution;
+
+    fn example() -> (Vec<Stack>, Vec<Move>) {
+        let input_str = std::fs::read_to_string("day5/input_example.txt").unwrap();
+
+        Day5::parse(&input_str).unwrap()
+    }
+
+    #[test]
+    fn history_works() {
+        let (stacks, moves) = example();
+
+        let mut history = History::record(&mut CrateMover9000, &stacks, &moves, Mode::Abort).unwrap();
+        assert_eq!((history.len(), history.position()), (4, 4));
+        assert_eq!(history.lifts(1).len(), 3);
+
+        for position in (0..=4).rev() {
+            let mut expected = stacks.clone();
+            operate(&mut CrateMover9000, &moves[..position], &mut expected, Mode::Abort).unwrap();
+
+            assert_eq!(history.stacks(), &expected[..]);
+            assert_eq!(history.step_back(), position > 0);
+        }
+
+        assert!(history.step_forward());
+        assert_eq!(history.stacks(), &[vec!['Z', 'N', 'D'], vec!['M', 'C'], vec!['P']]);
+    }
+
+    #[test]
+    fn top_after_works() {
+        let (stacks, moves) = example();
+
+        let mut history = History::record(&mut CrateMover9001, &stacks, &moves, Mode::Abort).unwrap();
+
+        assert_eq!(history.top_after(2, 3), Some('D'));
+        assert_eq!(history.top_after(0, 2), Some('D'));
+        assert_eq!(history.top_after(3, 2), None);
+        assert_eq!(history.top_after(9, 1), Some('M'));
+        assert_eq!(history.position(), 4);
+        assert_eq!(history.top_after(1, 4), None);
+    }
+}
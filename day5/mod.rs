@@ -0,0 +1,307 @@
+use std::fmt;
+
+use regex::Regex;
+
+use crate::{Error, Solution};
+
+pub mod crane;
+pub mod history;
+pub mod render;
+
+use crane::{operate, CrateMover9000, CrateMover9001, Mode};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<Stack>, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    const EXAMPLE_FILE: Option<&'static str> = Some("input_example.txt");
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1((stacks, moves): &Self::Input) -> Result<String, Error> {
+        let mut stacks = stacks.clone();
+
+        operate(&mut CrateMover9000, moves, &mut stacks, Mode::Abort)?;
+
+        top_crates(&stacks)
+    }
+
+    fn part2((stacks, moves): &Self::Input) -> Result<String, Error> {
+        let mut stacks = stacks.clone();
+
+        operate(&mut CrateMover9001, moves, &mut stacks, Mode::Abort)?;
+
+        top_crates(&stacks)
+    }
+}
+
+pub fn top_crates(stacks: &[Stack]) -> Result<String, Error> {
+    stacks.iter()
+        .enumerate()
+        .map(|(index, stack)| stack.last().copied()
+                .ok_or_else(|| Error::unsolvable(format!("stack {} is empty", index + 1))))
+        .collect()
+}
+
+type Location = usize;
+pub type Stack = Vec<char>;
+#[derive(Debug)]
+pub struct Move {
+    number_of_crates: usize,
+    origin: Location,
+    target: Location,
+    /// line of the move in the input, if it was parsed from one
+    line: Option<usize>,
+}
+
+/// Why a move cannot be carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveErrorKind {
+    UnknownStack { stack: Location, number_of_stacks: usize },
+    InsufficientCrates { stack: Location, needed: usize, available: usize },
+    SameStack { stack: Location },
+}
+
+/// A move which cannot be carried out, with its line in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveError {
+    pub line: Option<usize>,
+    pub kind: MoveErrorKind,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
+        }
+
+        match self.kind {
+            MoveErrorKind::UnknownStack { stack, number_of_stacks } => write!(f, "there is no stack {}, only stacks 1 to {}", stack, number_of_stacks),
+            MoveErrorKind::InsufficientCrates { stack, needed, available } => write!(f, "cannot take {} crates from stack {} holding {}", needed, stack, available),
+            MoveErrorKind::SameStack { stack } => write!(f, "cannot move crates from stack {} onto itself", stack),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+impl From<MoveError> for Error {
+    fn from(err: MoveError) -> Error {
+        let line = err.line;
+        let err = Error::unsolvable(MoveError { line: None, ..err }.to_string());
+
+        match line {
+            Some(line) => err.at_line(line),
+            None => err,
+        }
+    }
+}
+
+impl Move {
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Checks that the move can be carried out on `stacks`.
+    pub fn validate(&self, stacks: &[Stack]) -> Result<(), MoveError> {
+        let error = |kind| Err(MoveError { line: self.line, kind });
+
+        for stack in [self.origin, self.target] {
+            if stack == 0 || stack > stacks.len() {
+                return error(MoveErrorKind::UnknownStack { stack, number_of_stacks: stacks.len() });
+            }
+        }
+
+        if self.origin == self.target {
+            return error(MoveErrorKind::SameStack { stack: self.origin });
+        }
+
+        let available = stacks[self.origin - 1].len();
+
+        if available < self.number_of_crates {
+            return error(MoveErrorKind::InsufficientCrates { stack: self.origin, needed: self.number_of_crates, available });
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for Move {
+
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let re = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
+
+        let capture = re.captures(input)
+                .ok_or_else(|| Error::invalid(input, "a move like 'move 1 from 2 to 3'"))?;
+
+        let number = |index: usize| -> Result<usize, Error> {
+            let group = capture.get(index).unwrap();
+
+            group.as_str().parse()
+                .map_err(|_| Error::invalid(group.as_str(), "a number that fits into usize").at_column(group.start()))
+        };
+
+        Ok ( Move {
+            number_of_crates: number(1)?,
+            origin: number(2)?,
+            target: number(3)?,
+            line: None,
+        })
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<(Vec<Stack>, Vec<Move>), Error> {
+
+    let starting_stacks_lines: Vec<&str> = input_str.lines()
+            .take_while(|line| line.contains('['))
+            .collect();
+
+    if starting_stacks_lines.is_empty() {
+        let first_line = input_str.lines().next().unwrap_or_default();
+
+        return Err(Error::invalid(first_line, "a drawing of the crate stacks like '[A] [B]'").at_line(1));
+    }
+
+    let crate_layers: Vec<Vec<char>> = starting_stacks_lines.iter().rev()
+                    .map(|line| line.chars().skip(1).step_by(4).collect())
+                    .collect();
+
+    let number_of_stacks: usize = crate_layers[0].len();
+
+    let crate_stacks: Vec<Vec<char>> = (0..number_of_stacks)
+                                    .map(|stack_no| crate_layers.iter().map(|layer| layer.get(stack_no).copied().unwrap_or(' ')).take_while(|c| *c != ' ').collect())
+                                    .collect();
+
+    // the line numbering the stacks follows the drawing
+    let moves: Vec<Move> = input_str.lines()
+            .enumerate()
+            .skip(starting_stacks_lines.len() + 1)
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| match Move::try_from(line) {
+                Ok(amove) => Ok(Move { line: Some(index + 1), ..amove }),
+                Err(err) => Err(err.at_line(index + 1)),
+            })
+            .collect::<Result<_, _>>()?;
+
+    Ok((crate_stacks, moves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_example(filename: &str) -> (Vec<Stack>, Vec<Move>) {
+        let input_str = std::fs::read_to_string(filename).unwrap();
+
+        parse_input(&input_str).unwrap()
+    }
+
+    #[test]
+    fn move_from_str_works() {
+        const INPUT_STR: &str = "move 12323 from 1123 to 13239\n";
+
+        let result: Move = INPUT_STR.try_into().unwrap();
+
+        assert_eq!(result.number_of_crates, 12323);
+        assert_eq!(result.origin, 1123);
+        assert_eq!(result.target, 13239);
+
+        let result = Move::try_from("move 1 from a to 3");
+        assert!(result.is_err());
+
+        let result = Move::try_from("move 99999999999999999999999 from 1 to 3");
+        assert_eq!(result.unwrap_err(), Error::invalid("99999999999999999999999", "a number that fits into usize").at_column(5));
+    }
+
+    #[test]
+    fn parse_input_reports_line_of_bad_move() {
+        let input_str = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove one from 2 to 1\n";
+
+        let err = parse_input(input_str).unwrap_err();
+        assert_eq!(err.line(), Some(5));
+    }
+
+    #[test]
+    fn validate_works() {
+        let stacks = vec![vec!['A', 'B'], vec!['C']];
+
+        let check = |line: &str| Move::try_from(line).unwrap().validate(&stacks).map_err(|err| err.kind);
+
+        assert_eq!(check("move 2 from 1 to 2"), Ok(()));
+        assert_eq!(check("move 2 from 2 to 1"), Err(MoveErrorKind::InsufficientCrates { stack: 2, needed: 2, available: 1 }));
+        assert_eq!(check("move 1 from 0 to 1"), Err(MoveErrorKind::UnknownStack { stack: 0, number_of_stacks: 2 }));
+        assert_eq!(check("move 1 from 1 to 3"), Err(MoveErrorKind::UnknownStack { stack: 3, number_of_stacks: 2 }));
+        assert_eq!(check("move 1 from 2 to 2"), Err(MoveErrorKind::SameStack { stack: 2 }));
+    }
+
+    #[test]
+    fn parts_report_line_of_invalid_move() {
+        let input = parse_input("[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 3 from 2 to 1\n").unwrap();
+
+        assert_eq!(input.1[1].line(), Some(5));
+
+        let err = Day5::part2(&input).unwrap_err();
+        assert_eq!(err, Error::unsolvable("cannot take 3 crates from stack 2 holding 2").at_line(5));
+        assert_eq!(err.to_string(), "line 5, cannot take 3 crates from stack 2 holding 2");
+    }
+
+    #[test]
+    fn parse_input_works() {
+        const EXAMPLE_FILE: &str = "day5/input_example.txt";
+
+        let (stacks, moves) = parse_example(EXAMPLE_FILE);
+
+        assert_eq!(stacks[0], vec!['Z', 'N',]);
+        assert_eq!(stacks[1], vec!['M', 'C', 'D']);
+
+        assert_eq!(moves[0].number_of_crates, 1);
+        assert_eq!(moves[0].origin, 2);
+        assert_eq!(moves[0].target, 1);
+        assert_eq!(moves.len(), 4);
+    }
+
+    #[test]
+    fn do_move_works() {
+        const EXAMPLE_FILE: &str = "day5/input_example.txt";
+
+        let (mut stacks, moves) = parse_example(EXAMPLE_FILE);
+
+        operate(&mut CrateMover9000, &moves[..1], &mut stacks, Mode::Abort).unwrap();
+
+        assert_eq!(stacks[0], vec!['Z','N','D']);
+        assert_eq!(stacks[1], vec!['M','C']);
+    }
+
+    #[test]
+    fn do_move_9001_works() {
+        const EXAMPLE_FILE: &str = "day5/input_example.txt";
+
+        let (mut stacks, moves) = parse_example(EXAMPLE_FILE);
+
+        operate(&mut CrateMover9001, &moves[..2], &mut stacks, Mode::Abort).unwrap();
+
+        assert_eq!(stacks[2], vec!['P','Z','N','D']);
+    }
+
+    #[test]
+    fn do_example_2_works() {
+        const EXAMPLE_FILE: &str = "day5/input_example.txt";
+
+        let (mut stacks, moves) = parse_example(EXAMPLE_FILE);
+
+        operate(&mut CrateMover9001, &moves, &mut stacks, Mode::Abort).unwrap();
+
+        let res = top_crates(&stacks).unwrap();
+
+        println!("{}", res);
+        assert_eq!(res, "MCD");
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use super::crane::Lift;
+use super::Stack;
+
+/// Crates taken by a move: origin, target and how many, `None` for a skipped move.
+pub fn moved_crates(lifts: &[Lift]) -> Option<(usize, usize, usize)> {
+    let first = lifts.first()?;
+
+    Some((first.origin, first.target, lifts.iter().map(|lift| lift.number_of_crates).sum()))
+}
+
+/// Draws `stacks` like the input, the top `highlighted[i]` crates of stack `i + 1` are
+/// drawn as `<A>` instead of `[A]`.
+pub fn draw_stacks(stacks: &[Stack], highlighted: &[usize]) -> Vec<String> {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut lines: Vec<String> = (1..=height).rev()
+            .map(|level| {
+                let cells: Vec<String> = stacks.iter()
+                        .enumerate()
+                        .map(|(index, stack)| match stack.get(level - 1) {
+                            Some(acrate) if level + highlighted.get(index).copied().unwrap_or(0) > stack.len() => format!("<{}>", acrate),
+                            Some(acrate) => format!("[{}]", acrate),
+                            None => "   ".to_string(),
+                        })
+                        .collect();
+
+                cells.join(" ")
+            })
+            .collect();
+
+    let numbers: Vec<String> = (1..=stacks.len()).map(|number| format!("{:^3}", number)).collect();
+    lines.push(numbers.join(" "));
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day5::crane::{Crane, CrateMover9000};
+    use crate::day5::{parse_input, Move};
+
+    #[test]
+    fn draw_stacks_works() {
+        let input_str = std::fs::read_to_string("day5/input_example.txt").unwrap();
+        let (stacks, _) = parse_input(&input_str).unwrap();
+
+        let drawing = draw_stacks(&stacks, &[]);
+        assert_eq!(drawing, input_str.lines().take(4).collect::<Vec<&str>>());
+
+        assert_eq!(draw_stacks(&stacks, &[0, 2, 5]), vec![
+            "    <D>    ",
+            "[N] <C>    ",
+            "[Z] [M] <P>",
+            " 1   2   3 ",
+        ]);
+    }
+
+    #[test]
+    fn moved_crates_works() {
+        let amove = Move::try_from("move 3 from 1 to 2").unwrap();
+
+        assert_eq!(moved_crates(&CrateMover9000.lifts(&amove)), Some((1, 2, 3)));
+        assert_eq!(moved_crates(&[]), None);
+    }
+}
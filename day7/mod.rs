@@ -1,33 +1,44 @@
-
 use std::str::FromStr;
-use std::num::ParseIntError;
 use std::fmt;
 use indextree::{Arena, NodeId, Node};
 
-fn main() {
-    const INPUT_FILE: &str = "day7/input.txt";
+use crate::{Error, Solution};
 
-    let input_str = std::fs::read_to_string(INPUT_FILE).unwrap();
+const NEEDED_SPACE: usize = 30000000;
+const OVERALL_SPACE: usize = 70000000;
 
-    let lines : Vec<&str> = input_str.lines().collect();
-    let fs = FileSystem::from(lines);
+pub struct Day7;
 
-    println!("{}", fs);
+impl Solution for Day7 {
+    type Input = FileSystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let size: usize =  fs.get_dir_sizes().iter().filter(|x| **x < 100000).sum();
+    const EXAMPLE_FILE: Option<&'static str> = Some("test_input.txt");
 
-    println!("a: {}", size);
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let lines : Vec<&str> = input.lines().collect();
 
-    const NEEDED_SPACE: usize = 30000000;
-    const OVERALL_SPACE: usize = 70000000;
-    let overall_size: usize = *fs.get_dir_sizes().iter().max().unwrap();
-    let available_space = OVERALL_SPACE-overall_size;
-    let to_be_deleted_space = NEEDED_SPACE-available_space;
-    println!("{} of {} available, need to remove {}", available_space, NEEDED_SPACE, to_be_deleted_space);
+        FileSystem::try_from(lines)
+    }
 
-    let res_b: usize = *fs.get_dir_sizes().iter().filter(|x| **x>to_be_deleted_space).min().unwrap();
+    fn part1(fs: &FileSystem) -> Result<usize, Error> {
+        Ok(fs.get_dir_sizes().iter().filter(|x| **x < 100000).sum())
+    }
 
-    println!("b: {}, dist={}", res_b, res_b - to_be_deleted_space);
+    fn part2(fs: &FileSystem) -> Result<usize, Error> {
+        let overall_size: usize = *fs.get_dir_sizes().iter().max().unwrap();
+        let available_space = OVERALL_SPACE.checked_sub(overall_size)
+                .ok_or_else(|| Error::unsolvable("the file system does not fit on the disk"))?;
+        let to_be_deleted_space = NEEDED_SPACE.checked_sub(available_space)
+                .ok_or_else(|| Error::unsolvable("there is already enough free space"))?;
+
+        fs.get_dir_sizes().iter()
+            .filter(|x| **x>to_be_deleted_space)
+            .min()
+            .copied()
+            .ok_or_else(|| Error::unsolvable("no directory is large enough to free the needed space"))
+    }
 }
 
 #[derive(Debug)]
@@ -36,7 +47,7 @@ enum NodeData {
     Directory{ name: String }
 }
 
-struct FileSystem {
+pub struct FileSystem {
     arena: Arena<NodeData>,
     root: NodeId,
 }
@@ -47,8 +58,7 @@ impl FileSystem {
 
         if let Some(dir_node) = folder.children(&self.arena)
                 .filter_map(|node_id| self.arena.get(node_id))
-                .filter(|node| FileSystem::is_named(*node, name))
-                .next()
+                .find(|node| FileSystem::is_named(node, name))
         {
             return self.arena.get_node_id(dir_node)
         }
@@ -58,18 +68,17 @@ impl FileSystem {
 
     fn get_parentfolder(&self, folder: NodeId) -> Option<NodeId> {
         folder.ancestors(&self.arena)
-            .skip(1)
-            .next()
+            .nth(1)
     }
 
     fn is_named(node: &Node<NodeData>, aname: &str) -> bool {
 
         let data = node.get();
 
-        return match data {
+        match data {
             NodeData::Directory {name} => name == aname,
             _ => false,
-        };
+        }
     }
     
     fn display_node(&self, f: &mut fmt::Formatter, node_id: NodeId, depth: usize) -> fmt::Result {
@@ -79,10 +88,10 @@ impl FileSystem {
 
         match data {
             NodeData::File {name, size} => {
-                write!(f, "{}{} ({})\n", "\t".repeat(depth), name, size)?;
+                writeln!(f, "{}{} ({})", "\t".repeat(depth), name, size)?;
             },
             NodeData::Directory {name} => {
-                write!(f, "{}{} (dir)\n", "\t".repeat(depth), name)?;
+                writeln!(f, "{}{} (dir)", "\t".repeat(depth), name)?;
                 for child in node_id.children(&self.arena) {
                     self.display_node(f, child, depth+1)?;
                 }
@@ -128,7 +137,7 @@ impl FileSystem {
 
 impl fmt::Display for FileSystem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "/\n")?;
+        writeln!(f, "/")?;
         
         for node_id in self.root.children(&self.arena) { 
             self.display_node(f, node_id, 1)?;
@@ -138,9 +147,11 @@ impl fmt::Display for FileSystem {
     }
 }
 
-impl From<Vec<&str>> for FileSystem {
+impl TryFrom<Vec<&str>> for FileSystem {
+
+    type Error = Error;
 
-    fn from(lines: Vec<&str>) -> Self {
+    fn try_from(lines: Vec<&str>) -> Result<Self, Self::Error> {
         let mut arena = Arena::new();
 
         let name = "root".to_string();
@@ -153,33 +164,39 @@ impl From<Vec<&str>> for FileSystem {
         
         let mut current_directory = fs.root;
 
-        for line in lines {
-            
-            match Input::from_str(line) {
-                Ok(Input::DirectoryEntry{name}) => {
+        for (index, line) in lines.into_iter().enumerate() {
+
+            let input = Input::from_str(line).map_err(|err| err.at_line(index + 1))?;
+
+            match input {
+                Input::DirectoryEntry{name} => {
                     let new_directory = fs.arena.new_node(
                         NodeData::Directory{name}
                     );
                     current_directory.append(new_directory, &mut fs.arena);
                 },
-                Ok(Input::FileEntry{name, size}) => {
+                Input::FileEntry{name, size} => {
                     let new_directory = fs.arena.new_node(
                         NodeData::File{name, size}
                     );
                     current_directory.append(new_directory, &mut fs.arena);
                 },
-                Ok(Input::ChangeDirCmd{target}) => {
-                    current_directory = match &target[..] {
-                        "/" => fs.root,
-                        ".." => fs.get_parentfolder(current_directory).unwrap(),
-                        _ => fs.get_subfolder(current_directory, &target).unwrap(),
-                    }
+                Input::ChangeDirCmd{target} => {
+                    let found_directory = match &target[..] {
+                        "/" => Some(fs.root),
+                        ".." => fs.get_parentfolder(current_directory),
+                        _ => fs.get_subfolder(current_directory, &target),
+                    };
+
+                    current_directory = found_directory.ok_or_else(||
+                        Error::invalid(target, "a directory listed before").at_column(5).at_line(index + 1)
+                    )?;
                 }
-                _ => {},
+                Input::ListCmd => {},
             }
         }
 
-        fs
+        Ok(fs)
     }
 }
 
@@ -192,24 +209,15 @@ enum Input {
     FileEntry{name: String, size: usize}
 }
 
-#[derive(Debug)]
-struct ParseInputError;
-
-impl From<ParseIntError> for ParseInputError {
-    fn from(_err: ParseIntError) -> Self {
-        ParseInputError{}
-    }
-}
-
 impl FromStr for Input {
-    type Err = ParseInputError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.starts_with("$ cd") {
 
-            let target = s.split(" ")
-                    .last()
-                    .ok_or(ParseInputError{})?;
+            let target = s.split(' ')
+                    .nth(2)
+                    .ok_or_else(|| Error::invalid(s, "'$ cd <directory>'"))?;
             
             Ok(Input::ChangeDirCmd{target: target.into()})
 
@@ -218,9 +226,9 @@ impl FromStr for Input {
             Ok(Input::ListCmd)
 
         } else if s.starts_with("dir") {
-            let name = s.split(" ")
-                    .last()
-                    .ok_or(ParseInputError{})?;
+            let name = s.split(' ')
+                    .nth(1)
+                    .ok_or_else(|| Error::invalid(s, "'dir <name>'"))?;
             
             Ok(Input::DirectoryEntry{name: name.to_string()})
 
@@ -229,12 +237,13 @@ impl FromStr for Input {
             let mut split_iter = s.split(" ");
 
             let size_str: &str = split_iter.next()
-                                .ok_or(ParseInputError{})?;
+                                .ok_or_else(|| Error::invalid(s, "a command or a directory entry"))?;
 
-            let size: usize = size_str.parse()?;
+            let size: usize = size_str.parse()
+                                .map_err(|_| Error::invalid(size_str, "a command or the size of a file"))?;
 
             let name = split_iter.next()
-                       .ok_or(ParseInputError{})?;
+                       .ok_or_else(|| Error::invalid(s, "'<size> <file name>'"))?;
             
 
             Ok(Input::FileEntry{name: name.to_string(), size})
@@ -260,7 +269,7 @@ mod tests {
             "123123 as"
         ];
 
-        let fs = FileSystem::from(lines);
+        let fs = FileSystem::try_from(lines).unwrap();
 
         let root = fs.root;
 
@@ -298,4 +307,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unknown_directory_is_reported() {
+        let lines = vec![
+            "$ cd /",
+            "$ ls",
+            "dir abc",
+            "$ cd abd",
+        ];
+
+        let err = FileSystem::try_from(lines).err().unwrap();
+
+        assert_eq!(err, Error::invalid("abd", "a directory listed before").at_column(5).at_line(4));
+    }
+
+    #[test]
+    fn invalid_line_is_reported() {
+        let err = Input::from_str("12x3 a.dat").err().unwrap();
+
+        assert_eq!(err, Error::invalid("12x3", "a command or the size of a file"));
+    }
+
 }
@@ -0,0 +1,113 @@
+use std::fmt;
+
+/// Error shared by all days, pointing at the place in the input it was caused by.
+///
+/// Lines and columns are counted from 1. Parsers of single tokens create errors without
+/// a line and relative to the token, the caller then places them with `at_line` and
+/// `at_column`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A part of the input does not have the expected format.
+    Invalid {
+        line: Option<usize>,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    /// The input is well-formed, but the puzzle has no answer for it.
+    Unsolvable {
+        line: Option<usize>,
+        reason: String,
+    },
+}
+
+impl Error {
+    pub fn invalid(text: impl Into<String>, expected: impl Into<String>) -> Error {
+        Error::Invalid { line: None, column: 1, text: text.into(), expected: expected.into() }
+    }
+
+    pub fn unsolvable(reason: impl Into<String>) -> Error {
+        Error::Unsolvable { line: None, reason: reason.into() }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Error::Invalid { line, .. } | Error::Unsolvable { line, .. } => *line,
+        }
+    }
+
+    /// Sets the line number, if the error does not know it yet.
+    pub fn at_line(mut self, number: usize) -> Error {
+        match &mut self {
+            Error::Invalid { line, .. } | Error::Unsolvable { line, .. } => {
+                line.get_or_insert(number);
+            }
+        }
+
+        self
+    }
+
+    /// Moves the error `offset` characters to the right, used when the erroneous token
+    /// does not start at the beginning of the line.
+    pub fn at_column(mut self, offset: usize) -> Error {
+        if let Error::Invalid { column, .. } = &mut self {
+            *column += offset;
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(line) = self.line() {
+            write!(f, "line {}, ", line)?;
+        }
+
+        match self {
+            Error::Invalid { column, text, expected, .. } => {
+                write!(f, "column {}: found '{}', expected {}", column, text, expected)
+            },
+            Error::Unsolvable { reason, .. } => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses every line of `input` with `parse_line`, errors are placed at the line they occurred in.
+pub fn parse_lines<'a, T>(input: &'a str, parse_line: impl Fn(&'a str) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+    input.lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|err| err.at_line(index + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_display_works() {
+        let err = Error::invalid("Q", "A, B or C").at_column(2).at_line(312);
+        assert_eq!(err.to_string(), "line 312, column 3: found 'Q', expected A, B or C");
+
+        let err = Error::unsolvable("stack 2 is empty");
+        assert_eq!(err.to_string(), "stack 2 is empty");
+    }
+
+    #[test]
+    fn at_line_keeps_first_line() {
+        let err = Error::unsolvable("no badge").at_line(3).at_line(7);
+        assert_eq!(err.line(), Some(3));
+    }
+
+    #[test]
+    fn parse_lines_works() {
+        let res = parse_lines("1\n2\n3", |line| line.parse::<u32>().map_err(|_| Error::invalid(line, "a number")));
+        assert_eq!(res, Ok(vec![1, 2, 3]));
+
+        let res = parse_lines("1\nx\n3", |line| line.parse::<u32>().map_err(|_| Error::invalid(line, "a number")));
+        assert_eq!(res.unwrap_err().line(), Some(2));
+    }
+}
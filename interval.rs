@@ -0,0 +1,338 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// Interval of ordered points, always including its start and either including its end
+/// (closed) or not (half-open).
+///
+/// Intervals are ordered by start, then by end, a half-open interval coming before the
+/// closed one with the same end. Operations which need to know the point following another
+/// one, like `len` and `union`, are available for `Step` types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+    end_included: bool,
+}
+
+impl<T: Ord> Interval<T> {
+    /// `[start, end]`
+    ///
+    /// # Panics
+    ///
+    /// If `end` comes before `start`.
+    pub fn closed(start: T, end: T) -> Interval<T> {
+        assert!(start <= end, "interval ends before it starts");
+
+        Interval { start, end, end_included: true }
+    }
+
+    /// `[start, end)`, empty if `start == end`
+    ///
+    /// # Panics
+    ///
+    /// If `end` comes before `start`.
+    pub fn half_open(start: T, end: T) -> Interval<T> {
+        assert!(start <= end, "interval ends before it starts");
+
+        Interval { start, end, end_included: false }
+    }
+
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.end_included
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.end_included && self.start == self.end
+    }
+
+    pub fn contains(&self, point: &T) -> bool {
+        self.start <= *point && (*point < self.end || (self.end_included && *point == self.end))
+    }
+
+    /// Whether all points of `self` are in `other`, the empty interval is in every interval.
+    pub fn is_subset_of(&self, other: &Interval<T>) -> bool {
+        self.is_empty() || (other.start <= self.start && self.end_bound() <= other.end_bound())
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        let start = (&self.start).max(&other.start);
+        let (end, end_included) = self.end_bound().min(other.end_bound());
+
+        start < end || (end_included && start == end)
+    }
+
+    /// The end and whether it is included, which orders ends like intervals are ordered.
+    fn end_bound(&self) -> (&T, bool) {
+        (&self.end, self.end_included)
+    }
+}
+
+impl<T: Ord + Clone> Interval<T> {
+    /// Points in both intervals, `None` if there are none.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let (end, end_included) = self.end_bound().min(other.end_bound());
+
+        Some(Interval { start: (&self.start).max(&other.start).clone(), end: end.clone(), end_included })
+    }
+}
+
+/// Points with a next point, like the integers.
+pub trait Step: Ord + Copy {
+    /// The next point, `None` for the last one.
+    fn successor(self) -> Option<Self>;
+
+    /// Number of points from `start` up to `end`, without `end`.
+    fn distance(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_step {
+    ($($t:ty),*) => {
+        $(
+            impl Step for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn distance(start: Self, end: Self) -> u64 {
+                    (end as i128 - start as i128) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_step!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<T: Step> Interval<T> {
+    /// Number of points, saturating at `u64::MAX`.
+    pub fn len(&self) -> u64 {
+        let distance = T::distance(self.start, self.end);
+
+        if self.end_included { distance.saturating_add(1) } else { distance }
+    }
+
+    /// Number of points in both intervals.
+    pub fn overlap_len(&self, other: &Interval<T>) -> u64 {
+        self.intersection(other).map_or(0, |intersection| intersection.len())
+    }
+
+    /// First point after the interval, `None` if there is none.
+    fn after_end(&self) -> Option<T> {
+        if self.end_included { self.end.successor() } else { Some(self.end) }
+    }
+
+    /// Both intervals as one, `None` if there are points between them.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let (first, scnd) = if self.start <= other.start { (self, other) } else { (other, self) };
+
+        if first.after_end().is_some_and(|after_end| after_end < scnd.start) {
+            return None;
+        }
+
+        let last = if first.end_bound() >= scnd.end_bound() { first } else { scnd };
+
+        Some(Interval { start: first.start, end: last.end, end_included: last.end_included })
+    }
+
+    /// Points of `self` which are not in `other`, as up to two intervals.
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        let mut parts = Vec::new();
+
+        if self.start < other.start {
+            parts.push(Interval::half_open(self.start, other.start));
+        }
+
+        if let Some(start) = other.after_end() {
+            let rest = Interval { start, end: self.end, end_included: self.end_included };
+
+            if start <= self.end && !rest.is_empty() {
+                parts.push(rest);
+            }
+        }
+
+        parts
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let closing = if self.end_included { ']' } else { ')' };
+
+        write!(f, "[{}, {}{}", self.start, self.end, closing)
+    }
+}
+
+/// What an interval and its parts are called in parse errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Wording<'a> {
+    /// the expected format of the whole interval
+    pub format: &'a str,
+    pub interval: &'a str,
+    pub bound: &'a str,
+}
+
+const WORDING: Wording = Wording {
+    format: "an interval like '2-4', '[2, 4]' or '[2, 5)'",
+    interval: "an interval",
+    bound: "a bound",
+};
+
+impl<T: Ord + FromStr> Interval<T> {
+    /// Parses `a-b` as a closed interval, with errors using `wording`.
+    pub fn parse_closed(s: &str, wording: &Wording) -> Result<Self, Error> {
+        // skip the first character, which may be the sign of the start
+        let separator = s.char_indices()
+                .skip(1)
+                .find(|(_, c)| *c == '-')
+                .map(|(index, _)| index)
+                .ok_or_else(|| Error::invalid(s, wording.format))?;
+
+        Self::from_parts(s, &s[..separator], &s[separator + 1..], true, separator + 1, wording)
+    }
+
+    /// Parses the bounds `start_str` and `end_str` of `s`, the end starting at `end_column`.
+    fn from_parts(s: &str, start_str: &str, end_str: &str, end_included: bool, end_column: usize, wording: &Wording) -> Result<Self, Error> {
+        let start: T = start_str.parse().map_err(|_| Error::invalid(start_str, wording.bound).at_column(s.find(start_str).unwrap_or(0)))?;
+        let end: T = end_str.parse().map_err(|_| Error::invalid(end_str, wording.bound).at_column(end_column))?;
+
+        if start > end {
+            return Err(Error::invalid(s, format!("{} that does not end before it starts", wording.interval)));
+        }
+
+        Ok(Interval { start, end, end_included })
+    }
+}
+
+impl<T: Ord + FromStr> FromStr for Interval<T> {
+    type Err = Error;
+
+    /// Parses `[a, b]` and `[a, b)` like they are displayed, and `a-b` as a closed interval.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(inner) = s.strip_prefix('[') else {
+            return Self::parse_closed(s, &WORDING);
+        };
+
+        let (inner, end_included) = match (inner.strip_suffix(']'), inner.strip_suffix(')')) {
+            (Some(inner), _) => (inner, true),
+            (_, Some(inner)) => (inner, false),
+            _ => return Err(Error::invalid(s, WORDING.format)),
+        };
+
+        let (start_str, end_str) = inner.split_once(',').ok_or_else(|| Error::invalid(s, WORDING.format))?;
+        let end_column = 1 + start_str.len() + 1 + (end_str.len() - end_str.trim_start().len());
+
+        Self::from_parts(s, start_str.trim(), end_str.trim(), end_included, end_column, &WORDING)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_works() {
+        let closed = Interval::closed(2, 4);
+        let half_open = Interval::half_open(2, 4);
+
+        assert!(closed.contains(&4) && !half_open.contains(&4));
+        assert!(closed.contains(&2) && half_open.contains(&2));
+        assert!(!closed.contains(&1));
+        assert!(Interval::half_open(3, 3).is_empty());
+        assert!(!Interval::closed(3, 3).is_empty());
+    }
+
+    #[test]
+    fn intersection_works() {
+        assert_eq!(Interval::closed(2, 6).intersection(&Interval::closed(4, 8)), Some(Interval::closed(4, 6)));
+        assert_eq!(Interval::closed(2, 6).intersection(&Interval::half_open(4, 6)), Some(Interval::half_open(4, 6)));
+        assert_eq!(Interval::closed(5, 7).intersection(&Interval::closed(7, 9)), Some(Interval::closed(7, 7)));
+        assert_eq!(Interval::half_open(5, 7).intersection(&Interval::closed(7, 9)), None);
+        assert_eq!(Interval::closed(2, 3).intersection(&Interval::closed(4, 5)), None);
+
+        assert!(Interval::closed(2, 8).overlaps(&Interval::closed(3, 7)));
+        assert!(!Interval::closed(1, 2).overlaps(&Interval::closed(4, 100)));
+    }
+
+    #[test]
+    fn is_subset_of_works() {
+        assert!(Interval::closed(1, 2).is_subset_of(&Interval::closed(1, 4)));
+        assert!(!Interval::closed(1, 4).is_subset_of(&Interval::closed(1, 2)));
+        assert!(!Interval::closed(1, 4).is_subset_of(&Interval::half_open(1, 4)));
+        assert!(Interval::half_open(9, 9).is_subset_of(&Interval::closed(1, 2)));
+    }
+
+    #[test]
+    fn len_works() {
+        assert_eq!(Interval::closed(2, 4).len(), 3);
+        assert_eq!(Interval::half_open(2, 4).len(), 2);
+        assert_eq!(Interval::closed(-3i64, 3).len(), 7);
+        assert_eq!(Interval::closed(i64::MIN, i64::MAX).len(), u64::MAX);
+
+        assert_eq!(Interval::closed(2, 6).overlap_len(&Interval::closed(4, 8)), 3);
+        assert_eq!(Interval::closed(2, 3).overlap_len(&Interval::closed(4, 8)), 0);
+    }
+
+    #[test]
+    fn union_works() {
+        assert_eq!(Interval::closed(1, 3).union(&Interval::closed(4, 5)), Some(Interval::closed(1, 5)));
+        assert_eq!(Interval::closed(4, 5).union(&Interval::closed(1, 3)), Some(Interval::closed(1, 5)));
+        assert_eq!(Interval::half_open(1, 3).union(&Interval::closed(3, 5)), Some(Interval::closed(1, 5)));
+        assert_eq!(Interval::closed(1, 9).union(&Interval::half_open(3, 5)), Some(Interval::closed(1, 9)));
+        assert_eq!(Interval::half_open(1, 3).union(&Interval::closed(4, 5)), None);
+        assert_eq!(Interval::closed(0, u8::MAX).union(&Interval::closed(7, 9)), Some(Interval::closed(0, u8::MAX)));
+    }
+
+    #[test]
+    fn difference_works() {
+        assert_eq!(Interval::closed(1, 9).difference(&Interval::closed(3, 5)), vec![Interval::half_open(1, 3), Interval::closed(6, 9)]);
+        assert_eq!(Interval::closed(1, 9).difference(&Interval::half_open(3, 5)), vec![Interval::half_open(1, 3), Interval::closed(5, 9)]);
+        assert_eq!(Interval::closed(1, 5).difference(&Interval::closed(3, 9)), vec![Interval::half_open(1, 3)]);
+        assert_eq!(Interval::closed(3, 5).difference(&Interval::closed(1, 9)), vec![]);
+        assert_eq!(Interval::closed(1, 2).difference(&Interval::closed(5, 9)), vec![Interval::closed(1, 2)]);
+        assert_eq!(Interval::closed(1, 9).difference(&Interval::closed(1, 9)), vec![]);
+        assert_eq!(Interval::closed(0, u8::MAX).difference(&Interval::closed(7, u8::MAX)), vec![Interval::half_open(0, 7)]);
+    }
+
+    #[test]
+    fn ord_works() {
+        let mut intervals = vec![Interval::closed(2, 4), Interval::closed(1, 9), Interval::half_open(2, 4), Interval::closed(2, 3)];
+        intervals.sort();
+
+        assert_eq!(intervals, vec![Interval::closed(1, 9), Interval::closed(2, 3), Interval::half_open(2, 4), Interval::closed(2, 4)]);
+    }
+
+    #[test]
+    fn display_and_from_str_work() {
+        assert_eq!(Interval::closed(2, 4).to_string(), "[2, 4]");
+        assert_eq!(Interval::half_open(2, 4).to_string(), "[2, 4)");
+
+        assert_eq!("[2, 4)".parse(), Ok(Interval::half_open(2, 4)));
+        assert_eq!("[2,4]".parse(), Ok(Interval::closed(2, 4)));
+        assert_eq!("2-4".parse(), Ok(Interval::closed(2u64, 4)));
+        assert_eq!("-5--2".parse(), Ok(Interval::closed(-5, -2)));
+
+        assert_eq!("2-x".parse::<Interval<u64>>(), Err(Error::invalid("x", "a bound").at_column(2)));
+        assert_eq!("[2, x]".parse::<Interval<u64>>(), Err(Error::invalid("x", "a bound").at_column(4)));
+        assert!("4-2".parse::<Interval<u64>>().is_err());
+        assert!("[2, 4".parse::<Interval<u64>>().is_err());
+        assert!("24".parse::<Interval<u64>>().is_err());
+    }
+}
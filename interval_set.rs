@@ -0,0 +1,298 @@
+use std::collections::BTreeSet;
+
+use crate::interval::{Interval, Step};
+
+/// Set of points stored as sorted runs, which neither overlap nor touch each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    runs: Vec<Interval<T>>,
+}
+
+impl<T: Step> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { runs: Vec::new() }
+    }
+
+    /// Merges `intervals` into runs.
+    pub fn from_intervals(intervals: impl IntoIterator<Item = Interval<T>>) -> IntervalSet<T> {
+        let mut intervals: Vec<Interval<T>> = intervals.into_iter().filter(|interval| !interval.is_empty()).collect();
+        intervals.sort();
+
+        let mut runs: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+
+        for interval in intervals {
+            match runs.last().and_then(|last| last.union(&interval)) {
+                Some(union) => *runs.last_mut().unwrap() = union,
+                None => runs.push(interval),
+            }
+        }
+
+        IntervalSet { runs }
+    }
+
+    /// Adds `interval`, merging it with the runs it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self.runs.partition_point(|run| run.start() < interval.start() && run.union(&interval).is_none());
+
+        let mut merged = interval;
+        let mut last = first;
+
+        while let Some(union) = self.runs.get(last).and_then(|run| run.union(&merged)) {
+            merged = union;
+            last += 1;
+        }
+
+        self.runs.splice(first..last, [merged]);
+    }
+
+    pub fn runs(&self) -> &[Interval<T>] {
+        &self.runs
+    }
+
+    /// Number of points, saturating at `u64::MAX`.
+    pub fn len(&self) -> u64 {
+        self.runs.iter().fold(0u64, |len, run| len.saturating_add(run.len()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    pub fn contains(&self, point: &T) -> bool {
+        let index = self.runs.partition_point(|run| run.start() <= point);
+
+        index > 0 && self.runs[index - 1].contains(point)
+    }
+
+    /// Points of `within` which are not in the set.
+    pub fn gaps(&self, within: &Interval<T>) -> IntervalSet<T> {
+        let within_end: Boundary<T> = (*within.end(), within.is_closed());
+
+        // position up to which the points of `within` are dealt with
+        let mut done: Boundary<T> = (*within.start(), false);
+        let first = self.runs.partition_point(|run| (*run.end(), run.is_closed()) <= done);
+
+        let mut gaps = Vec::new();
+
+        for run in &self.runs[first..] {
+            let run_start = (*run.start(), false);
+
+            if run_start >= within_end {
+                break;
+            }
+
+            gaps.extend(between(done, run_start));
+            done = done.max((*run.end(), run.is_closed()));
+        }
+
+        gaps.extend(between(done, within_end));
+
+        IntervalSet { runs: gaps }
+    }
+}
+
+impl<T: Step> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+/// How many intervals cover each point, as sorted segments of the same depth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage<T> {
+    /// covered segments and their depth, segments of depth 0 are left out and adjacent
+    /// segments of the same depth are merged
+    segments: Vec<(Interval<T>, usize)>,
+}
+
+/// Position between two points, `(point, false)` lies just before `point` and
+/// `(point, true)` just after it.
+type Boundary<T> = (T, bool);
+
+/// Points between two boundaries, `None` if there are none.
+fn between<T: Step>((start, after_start): Boundary<T>, (end, after_end): Boundary<T>) -> Option<Interval<T>> {
+    let start = if after_start { start.successor()? } else { start };
+
+    match (start.cmp(&end), after_end) {
+        (std::cmp::Ordering::Less, false) => Some(Interval::half_open(start, end)),
+        (std::cmp::Ordering::Less | std::cmp::Ordering::Equal, true) => Some(Interval::closed(start, end)),
+        _ => None,
+    }
+}
+
+impl<T: Step> Coverage<T> {
+    /// Sweeps over the start and end of every interval.
+    pub fn new(intervals: impl IntoIterator<Item = Interval<T>>) -> Coverage<T> {
+        let mut events: Vec<(Boundary<T>, isize)> = Vec::new();
+
+        for interval in intervals.into_iter().filter(|interval| !interval.is_empty()) {
+            events.push(((*interval.start(), false), 1));
+            events.push(((*interval.end(), interval.is_closed()), -1));
+        }
+
+        events.sort();
+
+        let mut segments: Vec<(Interval<T>, usize)> = Vec::new();
+        let mut depth = 0;
+
+        for (index, (boundary, change)) in events.iter().enumerate() {
+            depth += change;
+
+            let Some((next_boundary, _)) = events.get(index + 1) else {
+                break;
+            };
+
+            if depth > 0 && boundary != next_boundary {
+                let Some(segment) = between(*boundary, *next_boundary) else {
+                    continue;
+                };
+
+                // an interval ending right before another one starts does not change the depth
+                let merged = segments.last()
+                        .filter(|(_, last_depth)| *last_depth == depth as usize)
+                        .and_then(|(last, _)| last.union(&segment));
+
+                match merged {
+                    Some(union) => segments.last_mut().unwrap().0 = union,
+                    None => segments.push((segment, depth as usize)),
+                }
+            }
+        }
+
+        Coverage { segments }
+    }
+
+    pub fn segments(&self) -> &[(Interval<T>, usize)] {
+        &self.segments
+    }
+
+    /// Points covered by at least `depth` intervals.
+    pub fn at_least(&self, depth: usize) -> IntervalSet<T> {
+        IntervalSet::from_intervals(self.segments.iter()
+            .filter(|(_, segment_depth)| *segment_depth >= depth)
+            .map(|(segment, _)| *segment))
+    }
+
+    /// Points covered by any interval.
+    pub fn covered(&self) -> IntervalSet<T> {
+        self.at_least(1)
+    }
+
+    /// The highest depth and the first segment with it, `None` if nothing is covered.
+    pub fn max_depth(&self) -> Option<(usize, Interval<T>)> {
+        self.segments.iter()
+            .map(|(segment, depth)| (*depth, *segment))
+            .reduce(|max, current| if current.0 > max.0 { current } else { max })
+    }
+}
+
+/// Indices of every two overlapping `intervals`, the one starting first (or with the lower
+/// index) first.
+///
+/// Sweeps over the starts while keeping the intervals not ended yet ordered by their end,
+/// which takes O(n log n + k) for k overlapping pairs.
+pub fn overlapping_pairs<T: Ord + Copy>(intervals: &[Interval<T>]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..intervals.len())
+            .filter(|index| !intervals[*index].is_empty())
+            .collect();
+    order.sort_by_key(|index| (*intervals[*index].start(), *index));
+
+    let mut open: BTreeSet<(Boundary<T>, usize)> = BTreeSet::new();
+    let mut pairs = Vec::new();
+
+    for index in order {
+        let interval = &intervals[index];
+        let start: Boundary<T> = (*interval.start(), false);
+
+        while open.first().is_some_and(|(end, _)| *end <= start) {
+            open.pop_first();
+        }
+
+        pairs.extend(open.iter().map(|(_, other)| (*other, index)));
+
+        open.insert(((*interval.end(), interval.is_closed()), index));
+    }
+
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_intervals_works() {
+        let set = IntervalSet::from_intervals([Interval::closed(5, 7), Interval::closed(1, 2), Interval::closed(3, 3), Interval::half_open(9, 12), Interval::closed(10, 11)]);
+
+        assert_eq!(set.runs(), &[Interval::closed(1, 3), Interval::closed(5, 7), Interval::half_open(9, 12)]);
+        assert_eq!(set.len(), 3 + 3 + 3);
+        assert!(set.contains(&6) && set.contains(&9));
+        assert!(!set.contains(&4) && !set.contains(&12) && !set.contains(&0));
+    }
+
+    #[test]
+    fn insert_works() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::closed(1, 3));
+        set.insert(Interval::closed(7, 9));
+        set.insert(Interval::closed(4, 6));
+
+        assert_eq!(set.runs(), &[Interval::closed(1, 9)]);
+
+        let mut set = IntervalSet::from_intervals([Interval::closed(1, 2), Interval::closed(5, 6), Interval::closed(9, 10), Interval::closed(15, 20)]);
+        set.insert(Interval::closed(12, 12));
+        set.insert(Interval::half_open(4, 4));
+        assert_eq!(set.runs(), &[Interval::closed(1, 2), Interval::closed(5, 6), Interval::closed(9, 10), Interval::closed(12, 12), Interval::closed(15, 20)]);
+
+        set.insert(Interval::closed(6, 13));
+        set.insert(Interval::closed(0, 0));
+        assert_eq!(set.runs(), &[Interval::closed(0, 2), Interval::closed(5, 13), Interval::closed(15, 20)]);
+    }
+
+    #[test]
+    fn gaps_work() {
+        let set = IntervalSet::from_intervals([Interval::closed(2, 4), Interval::closed(7, 8)]);
+
+        let gaps = set.gaps(&Interval::closed(1, 10));
+        assert_eq!(gaps.runs(), &[Interval::half_open(1, 2), Interval::half_open(5, 7), Interval::closed(9, 10)]);
+        assert_eq!(gaps.len(), 1 + 2 + 2);
+
+        assert!(set.gaps(&Interval::closed(7, 8)).is_empty());
+        assert_eq!(set.gaps(&Interval::closed(3, 7)).runs(), &[Interval::half_open(5, 7)]);
+        assert_eq!(set.gaps(&Interval::half_open(5, 7)).runs(), &[Interval::half_open(5, 7)]);
+        assert!(set.gaps(&Interval::half_open(5, 5)).is_empty());
+    }
+
+    #[test]
+    fn coverage_works() {
+        let coverage = Coverage::new([Interval::closed(2, 4), Interval::closed(6, 8), Interval::closed(2, 3), Interval::closed(4, 5), Interval::closed(6, 6), Interval::closed(2, 6)]);
+
+        assert_eq!(coverage.covered().runs(), &[Interval::closed(2, 8)]);
+        assert_eq!(coverage.at_least(2).runs(), &[Interval::closed(2, 6)]);
+        assert_eq!(coverage.at_least(3).len(), 4);
+        assert_eq!(coverage.max_depth(), Some((3, Interval::closed(2, 4))));
+
+        assert_eq!(Coverage::<u64>::new([]).max_depth(), None);
+    }
+
+    #[test]
+    fn overlapping_pairs_works() {
+        let intervals = [Interval::closed(2, 4), Interval::half_open(5, 7), Interval::closed(4, 5), Interval::half_open(3, 3), Interval::closed(7, 9), Interval::closed(1, 9)];
+
+        let mut pairs = overlapping_pairs(&intervals);
+        pairs.sort();
+
+        assert_eq!(pairs, vec![(0, 2), (2, 1), (5, 0), (5, 1), (5, 2), (5, 4)]);
+    }
+
+    #[test]
+    fn coverage_handles_last_point() {
+        let coverage = Coverage::new([Interval::closed(250u8, u8::MAX), Interval::closed(u8::MAX, u8::MAX)]);
+
+        assert_eq!(coverage.segments(), &[(Interval::half_open(250, u8::MAX), 1), (Interval::closed(u8::MAX, u8::MAX), 2)]);
+    }
+}
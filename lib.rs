@@ -0,0 +1,89 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+mod bench;
+mod error;
+
+pub use bench::{BenchConfig, Stats, Timings};
+pub use error::{parse_lines, Error};
+
+pub mod interval;
+pub mod interval_set;
+
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day7;
+
+/// A single day of the advent calendar.
+///
+/// The input is parsed once by `parse`, both parts then work on the parsed
+/// representation. Malformed input and inputs without an answer are reported as `Error`.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Name of the bundled example input inside the day's directory, if there is one.
+    const EXAMPLE_FILE: Option<&'static str> = None;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Type erased entry of a `Solution`, so that all days can be kept in one list.
+pub struct Day {
+    pub number: u32,
+    example_file: Option<&'static str>,
+    pub run: fn(&str) -> Result<Answers, Error>,
+    pub bench: fn(&str, &BenchConfig) -> Result<Timings, Error>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u32) -> Day {
+        Day { number, example_file: S::EXAMPLE_FILE, run: run::<S>, bench: bench::bench::<S> }
+    }
+
+    pub fn directory(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("day{}", self.number))
+    }
+
+    pub fn input_file(&self) -> PathBuf {
+        self.directory().join("input.txt")
+    }
+
+    pub fn example_file(&self) -> Option<PathBuf> {
+        self.example_file.map(|name| self.directory().join(name))
+    }
+}
+
+fn run<S: Solution>(input: &str) -> Result<Answers, Error> {
+    let parsed = S::parse(input)?;
+
+    Ok(Answers {
+        part1: S::part1(&parsed)?.to_string(),
+        part2: S::part2(&parsed)?.to_string(),
+    })
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day7::Day7>(7),
+];
+
+pub fn get_day(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}